@@ -22,4 +22,42 @@ pub enum EscrowError {
     VaultOwnerMismatch,
     #[msg("Deadline has passed.")]
     DeadlinePassed,
+    #[msg("Invalid arbiter panel.")]
+    InvalidPanel,
+    #[msg("Arbiter has already voted.")]
+    AlreadyVoted,
+    #[msg("Stake below the configured minimum.")]
+    InsufficientStake,
+    #[msg("Arbiter pool is full.")]
+    ArbiterPoolFull,
+    #[msg("No eligible arbiter in the pool.")]
+    NoEligibleArbiter,
+    #[msg("Deadline has not passed yet.")]
+    DeadlineNotReached,
+    #[msg("Fee exceeds the configured cap.")]
+    FeeTooHigh,
+    #[msg("Arbiter token account required.")]
+    MissingArbiterAccount,
+    #[msg("Dispute bond accounts required.")]
+    MissingBondAccounts,
+    #[msg("Invalid milestone.")]
+    InvalidMilestone,
+    #[msg("Invalid vesting schedule.")]
+    InvalidVesting,
+    #[msg("Invalid subscription schedule.")]
+    InvalidSubscription,
+    #[msg("Seller collateral has not been posted.")]
+    CollateralNotPosted,
+    #[msg("Buyer token account required for the late penalty.")]
+    MissingPenaltyAccount,
+    #[msg("Invalid payout split.")]
+    InvalidPayoutSplit,
+    #[msg("Treasury or referrer token account required for the protocol fee.")]
+    MissingFeeAccount,
+    #[msg("Only the program upgrade authority can initialize the config.")]
+    NotUpgradeAuthority,
+    #[msg("Everything has vested; nothing is left to dispute.")]
+    FullyVested,
+    #[msg("Set the arbiter fee as either a flat amount or a share of the deal, not both.")]
+    InvalidArbiterFee,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{PayoutShare, TimeoutFallback};
+
 #[event]
 pub struct DealInitiated {
     pub seller: Pubkey,
@@ -11,9 +13,25 @@ pub struct DealInitiated {
 #[event]
 pub struct DealFunded {
     pub buyer: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DealPartiallyFunded {
+    pub escrow: Pubkey,
+    pub deposit: u64,
+    pub funded_amount: u64,
     pub amount: u64,
 }
 
+#[event]
+pub struct DealCancelled {
+    pub escrow: Pubkey,
+    pub by: Pubkey,
+    pub refunded: u64,
+}
+
 #[event]
 pub struct DealDisputed {
     pub by: Pubkey,
@@ -22,14 +40,337 @@ pub struct DealDisputed {
 #[event]
 pub struct DealResolved {
     pub verdict: u8,
+    pub buyer_bps: u16,
 }
 
 #[event]
 pub struct DealReleased {
     pub amount: u64,
+    pub arbiter_fee: u64,
+    pub late_penalty: u64, // Redirected from the seller to the buyer
 }
 
 #[event]
 pub struct DealRefunded {
     pub amount: u64,
+    pub arbiter_fee: u64,
+}
+
+#[event]
+pub struct PanelCreated {
+    pub escrow: Pubkey,
+    pub threshold: u8,
+    pub arbiters: Vec<Pubkey>,
+}
+
+#[event]
+pub struct PanelVoteCast {
+    pub escrow: Pubkey,
+    pub arbiter: Pubkey,
+    pub verdict: u8,
+    pub buyer_bps: u16,
+    pub matching_votes: u8,
+    pub votes_cast: u8,
+}
+
+#[event]
+pub struct DealSettled {
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+    pub arbiter_fee: u64,
+    pub late_penalty: u64, // Included in buyer_amount
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub min_stake: u64,
+}
+
+#[event]
+pub struct DisputePolicyUpdated {
+    pub resolution_window: i64,
+    pub timeout_fallback: TimeoutFallback,
+    pub backup_arbiter: Pubkey,
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub max_arbiter_fee_bps: u16,
+    pub max_referral_bps: u16,
+}
+
+#[event]
+pub struct ArbiterRegistered {
+    pub arbiter: Pubkey,
+    pub stake: u64,
+}
+
+#[event]
+pub struct ArbiterStatusChanged {
+    pub arbiter: Pubkey,
+    pub active: bool,
+}
+
+#[event]
+pub struct ArbiterDeactivated {
+    pub arbiter: Pubkey,
+    pub unstake_at: i64,
+}
+
+#[event]
+pub struct ArbiterStakeWithdrawn {
+    pub arbiter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ArbiterSlashed {
+    pub arbiter: Pubkey,
+    pub amount: u64,
+    pub overturned: bool,
+    pub remaining_stake: u64,
+}
+
+#[event]
+pub struct ArbiterAssigned {
+    pub escrow: Pubkey,
+    pub arbiter: Pubkey,
+    pub slot: u64,
+    pub pool_size: u32,
+}
+
+#[event]
+pub struct ArbiterTimedOut {
+    pub escrow: Pubkey,
+    pub arbiter: Pubkey,
+    pub resolve_by: i64,
+    pub fallback: TimeoutFallback,
+    pub new_arbiter: Pubkey, // Set when the dispute was escalated
+}
+
+#[event]
+pub struct ArbiterChanged {
+    pub escrow: Pubkey,
+    pub old_arbiter: Pubkey,
+    pub new_arbiter: Pubkey,
+    pub by_admin: bool,
+}
+
+#[event]
+pub struct DisputeBondPosted {
+    pub escrow: Pubkey,
+    pub by: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DisputeBondSettled {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct DealMutuallySettled {
+    pub escrow: Pubkey,
+    pub buyer_bps: u16,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+    pub bond_returned: u64,
+}
+
+#[event]
+pub struct PayoutAddressChanged {
+    pub escrow: Pubkey,
+    pub old_payout_to: Pubkey,
+    pub new_payout_to: Pubkey,
+}
+
+#[event]
+pub struct RefundAddressChanged {
+    pub escrow: Pubkey,
+    pub old_refund_to: Pubkey,
+    pub new_refund_to: Pubkey,
+}
+
+#[event]
+pub struct MilestonesCreated {
+    pub escrow: Pubkey,
+    pub count: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct MilestoneDisputed {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub by: Pubkey,
+}
+
+#[event]
+pub struct MilestoneVoteCast {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub arbiter: Pubkey,
+    pub verdict: u8,
+    pub buyer_bps: u16,
+    pub matching_votes: u8,
+}
+
+#[event]
+pub struct MilestoneResolved {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub verdict: u8,
+    pub buyer_bps: u16,
+}
+
+#[event]
+pub struct MilestoneSettled {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct VestedWithdrawn {
+    pub escrow: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct PeriodsClaimed {
+    pub escrow: Pubkey,
+    pub periods: u32,
+    pub amount: u64,
+    pub periods_claimed: u32,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub escrow: Pubkey,
+    pub seller_amount: u64,
+    pub refunded: u64,
+    pub periods_claimed: u32,
+}
+
+#[event]
+pub struct SwapConfigured {
+    pub escrow: Pubkey,
+    pub swap_mint: Pubkey,
+    pub swap_amount: u64,
+    pub swap_by: i64,
+}
+
+#[event]
+pub struct SwapDeposited {
+    pub escrow: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SwapExecuted {
+    pub escrow: Pubkey,
+    pub buyer_leg: u64,
+    pub seller_leg: u64,
+}
+
+#[event]
+pub struct SwapDepositReclaimed {
+    pub escrow: Pubkey,
+    pub by: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CollateralPosted {
+    pub escrow: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CollateralSettled {
+    pub escrow: Pubkey,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+}
+
+#[event]
+pub struct DeliveryMarked {
+    pub escrow: Pubkey,
+    pub delivered_at: i64,
+    pub deliver_by: i64,
+}
+
+#[event]
+pub struct BuyerCancelled {
+    pub escrow: Pubkey,
+    pub refunded: u64,
+    pub cancel_fee: u64,
+    pub fee_waived: bool,
+}
+
+#[event]
+pub struct PayoutSplitSet {
+    pub escrow: Pubkey,
+    pub shares: Vec<PayoutShare>,
+}
+
+#[event]
+pub struct PayoutSplitPaid {
+    pub escrow: Pubkey,
+    pub payout: u64,
+    pub seller_remainder: u64,
+}
+
+#[event]
+pub struct ProtocolFeePaid {
+    pub escrow: Pubkey,
+    pub treasury_fee: u64,
+    pub referrer: Pubkey,
+    pub referral_fee: u64,
+}
+
+#[event]
+pub struct DealAmended {
+    pub escrow: Pubkey,
+    pub nonce: u64,
+    pub old_amount: u64,
+    pub new_amount: u64,
+    pub old_dispute_by: i64,
+    pub new_dispute_by: i64,
+    pub old_fund_by: i64,
+    pub new_fund_by: i64,
+    pub old_deliver_by: i64,
+    pub new_deliver_by: i64,
+}
+
+#[event]
+pub struct DealPartiallyRefunded {
+    pub escrow: Pubkey,
+    pub refunded: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct EscrowMigrated {
+    pub escrow: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::DealAmended;
+use crate::state::{Config, EscrowState, EscrowStatus};
+use crate::utils::{bps_of, validate_deadlines};
+
+// Renegotiates an unfunded deal in place; both parties must sign. A bps-based arbiter
+// fee is rescaled to the new amount.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct Amend<'info> {
+    pub seller: Signer<'info>,
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+        constraint = escrow_state.funded_amount == 0 @ EscrowError::InvalidState,
+        // Milestone tranches are fixed to `amount`.
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handle_amend(
+    ctx: Context<Amend>,
+    _deal_id: [u8; 16],
+    amount: u64,
+    dispute_by: i64,
+    fund_by: i64,
+    deliver_by: i64,
+) -> Result<()> {
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    let now = Clock::get()?.unix_timestamp;
+    validate_deadlines(now, &[dispute_by, fund_by, deliver_by])?;
+
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    if state.arbiter_fee_bps > 0 {
+        state.arbiter_fee = bps_of(amount, state.arbiter_fee_bps)?;
+    }
+    require!(
+        state.arbiter_fee <= bps_of(amount, ctx.accounts.config.max_arbiter_fee_bps)?,
+        EscrowError::FeeTooHigh
+    );
+    if state.periods > 0 {
+        // u64::is_multiple_of needs Rust 1.87, newer than the SBF toolchain.
+        #[allow(clippy::manual_is_multiple_of)]
+        let whole_periods = amount % state.periods as u64 == 0;
+        require!(whole_periods, EscrowError::InvalidSubscription);
+        state.period_amount = amount / state.periods as u64;
+    }
+
+    let (old_amount, old_dispute_by, old_fund_by, old_deliver_by) = (
+        state.amount,
+        state.dispute_by,
+        state.fund_by,
+        state.deliver_by,
+    );
+    state.amount = amount;
+    state.vest_total = amount;
+    state.dispute_by = dispute_by;
+    state.fund_by = fund_by;
+    state.deliver_by = deliver_by;
+    state.nonce = state.nonce.checked_add(1).ok_or(EscrowError::Overflow)?;
+
+    emit!(DealAmended {
+        escrow: escrow_key,
+        nonce: state.nonce,
+        old_amount,
+        new_amount: amount,
+        old_dispute_by,
+        new_dispute_by: dispute_by,
+        old_fund_by,
+        new_fund_by: fund_by,
+        old_deliver_by,
+        new_deliver_by: deliver_by,
+    });
+
+    Ok(())
+}
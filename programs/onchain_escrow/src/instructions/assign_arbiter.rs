@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterAssigned;
+use crate::state::{ArbiterPool, EscrowState, EscrowStatus};
+use crate::utils::{arbiter_selection_seed, ARBITER_ASSIGNMENT_DELAY_SLOTS};
+
+// Permissionless: draws the pool arbiter for a dispute once its assignment slot has
+// passed. If nobody called in time and the slot hash is gone, the draw is re-armed.
+// Milestone disputes leave the deal itself `Funded`.
+#[derive(Accounts)]
+pub struct AssignArbiter<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.status == EscrowStatus::Disputed || (escrow_state.is_milestone && escrow_state.status == EscrowStatus::Funded) @ EscrowError::InvalidState,
+        constraint = escrow_state.arbiter == Pubkey::default() @ EscrowError::InvalidArbiter,
+        constraint = escrow_state.assign_slot > 0 @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(seeds = [b"arbiter_pool"], bump = arbiter_pool.bump)]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    /// CHECK: SlotHashes sysvar, read raw for the assignment seed
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+pub fn handle_assign_arbiter(ctx: Context<AssignArbiter>) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let current_slot = Clock::get()?.slot;
+    require!(
+        current_slot > state.assign_slot,
+        EscrowError::DeadlineNotReached
+    );
+
+    let Some((seed, slot)) =
+        arbiter_selection_seed(&ctx.accounts.slot_hashes, &escrow_key, state.assign_slot)?
+    else {
+        state.assign_slot = current_slot
+            .checked_add(ARBITER_ASSIGNMENT_DELAY_SLOTS)
+            .ok_or(EscrowError::Overflow)?;
+        return Ok(());
+    };
+
+    let pool = &ctx.accounts.arbiter_pool;
+    let arbiter = pool
+        .select(seed, &[state.seller, state.buyer])
+        .ok_or(EscrowError::NoEligibleArbiter)?;
+    state.arbiter = arbiter;
+    state.assign_slot = 0;
+
+    emit!(ArbiterAssigned {
+        escrow: escrow_key,
+        arbiter,
+        slot,
+        pool_size: pool.arbiters.len() as u32,
+    });
+
+    Ok(())
+}
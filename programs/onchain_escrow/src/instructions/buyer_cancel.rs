@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{BuyerCancelled, CollateralSettled};
+use crate::state::{Config, EscrowState, EscrowStatus, PayoutSplit};
+use crate::utils::{bps_of, settle_payout, vault_signer_seeds, PayoutAccounts};
+
+// The buyer may back out of a funded deal until the seller marks delivery. The
+// cancellation fee goes to the seller unless the seller co-signs to waive it.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct BuyerCancel<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(constraint = seller.key() == escrow_state.seller @ EscrowError::Unauthorized)]
+    pub seller: Option<Signer<'info>>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = escrow_state.delivered_at == 0 @ EscrowError::InvalidState,
+        // Scheduled deals earn the seller a share over time; they end through
+        // `cancel_subscription` or a dispute instead.
+        constraint = escrow_state.periods == 0 && escrow_state.vest_end == 0 @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    /// CHECK: seller payout owner recorded in escrow_state
+    #[account(address = escrow_state.payout_to @ EscrowError::Unauthorized)]
+    pub seller_payout: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = seller_payout
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    // Required when the deal has a payout split; recipient token accounts follow in
+    // `remaining_accounts`, in table order.
+    #[account(
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Option<Account<'info, PayoutSplit>>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Protocol fee recipients, required only when the respective share is non-zero.
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = referrer_ata.owner == escrow_state.referrer @ EscrowError::Unauthorized,
+        constraint = referrer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub referrer_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_buyer_cancel<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyerCancel<'info>>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let amount = state.amount;
+    require!(
+        ctx.accounts.vault_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    let fee_waived = ctx.accounts.seller.is_some();
+    let cancel_fee = if fee_waived {
+        0
+    } else {
+        bps_of(amount, state.cancel_fee_bps)?
+    };
+    let refunded = amount - cancel_fee;
+    // The seller is not at fault, so any collateral goes back with the fee.
+    let seller_collateral = state.collateral_posted;
+    state.collateral_posted = 0;
+
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: Some(ctx.accounts.buyer_ata.to_account_info()),
+        seller_ata: Some(ctx.accounts.seller_ata.to_account_info()),
+        arbiter_ata: None,
+        treasury_ata: ctx.accounts.treasury_ata.as_ref(),
+        referrer_ata: ctx.accounts.referrer_ata.as_ref(),
+        payout_split: ctx.accounts.payout_split.as_ref(),
+        split_recipients: ctx.remaining_accounts,
+    };
+    settle_payout(
+        &accounts,
+        state,
+        escrow_state_key,
+        refunded,
+        cancel_fee,
+        false,
+        (0, seller_collateral),
+    )?;
+
+    state.amount = 0;
+    state.status = EscrowStatus::Cancelled;
+
+    emit!(BuyerCancelled {
+        escrow: escrow_state_key,
+        refunded,
+        cancel_fee,
+        fee_waived,
+    });
+    if seller_collateral > 0 {
+        emit!(CollateralSettled {
+            escrow: escrow_state_key,
+            buyer_amount: 0,
+            seller_amount: seller_collateral,
+        });
+    }
+
+    Ok(())
+}
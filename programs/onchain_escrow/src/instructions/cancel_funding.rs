@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DealCancelled;
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::{vault_signer_seeds, vault_transfer};
+
+// The buyer or seller may cancel a partially funded deal at any time; once the funding
+// deadline has passed anyone may. Deposits go back to the refund address.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct CancelFunding<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.status == EscrowStatus::PartiallyFunded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_cancel_funding(ctx: Context<CancelFunding>, _deal_id: [u8; 16]) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let caller = ctx.accounts.caller.key();
+    if caller != state.buyer && caller != state.seller {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            state.fund_by > 0 && now > state.fund_by,
+            EscrowError::Unauthorized
+        );
+    }
+
+    let refunded = state.funded_amount;
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.buyer_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        refunded,
+    )?;
+
+    state.funded_amount = 0;
+    state.status = EscrowStatus::Cancelled;
+
+    emit!(DealCancelled {
+        escrow: escrow_state_key,
+        by: caller,
+        refunded,
+    });
+
+    Ok(())
+}
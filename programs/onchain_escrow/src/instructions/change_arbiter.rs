@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterChanged;
+use crate::state::{
+    ArbiterProfile, ArbiterStatus, Config, EscrowState, EscrowStatus, MilestoneSchedule,
+    MilestoneStatus,
+};
+
+#[derive(Accounts)]
+pub struct ChangeArbiter<'info> {
+    pub buyer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: replacement arbiter, validated through its registry profile
+    pub new_arbiter: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"arbiter", new_arbiter.key().as_ref()],
+        bump = new_arbiter_profile.bump,
+        constraint = new_arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub new_arbiter_profile: Account<'info, ArbiterProfile>,
+    // Required on milestone deals, whose disputes run per milestone.
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+}
+
+pub fn handle_change_arbiter(ctx: Context<ChangeArbiter>) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    apply_arbiter_change(
+        &mut ctx.accounts.escrow_state,
+        ctx.accounts.milestone_schedule.as_deref_mut(),
+        escrow_key,
+        ctx.accounts.new_arbiter.key(),
+        ctx.accounts.config.resolution_window,
+        false,
+    )
+}
+
+#[derive(Accounts)]
+pub struct AdminChangeArbiter<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ EscrowError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// The admin may only replace arbiters that are registered, e.g. after suspending one.
+    #[account(
+        seeds = [b"arbiter", escrow_state.arbiter.as_ref()],
+        bump = current_arbiter_profile.bump,
+    )]
+    pub current_arbiter_profile: Account<'info, ArbiterProfile>,
+    /// CHECK: replacement arbiter, validated through its registry profile
+    pub new_arbiter: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"arbiter", new_arbiter.key().as_ref()],
+        bump = new_arbiter_profile.bump,
+        constraint = new_arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub new_arbiter_profile: Account<'info, ArbiterProfile>,
+    // Required on milestone deals, whose disputes run per milestone.
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Option<Account<'info, MilestoneSchedule>>,
+}
+
+pub fn handle_admin_change_arbiter(ctx: Context<AdminChangeArbiter>) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    apply_arbiter_change(
+        &mut ctx.accounts.escrow_state,
+        ctx.accounts.milestone_schedule.as_deref_mut(),
+        escrow_key,
+        ctx.accounts.new_arbiter.key(),
+        ctx.accounts.config.resolution_window,
+        true,
+    )
+}
+
+fn apply_arbiter_change(
+    state: &mut EscrowState,
+    milestone_schedule: Option<&mut MilestoneSchedule>,
+    escrow_key: Pubkey,
+    new_arbiter: Pubkey,
+    resolution_window: i64,
+    by_admin: bool,
+) -> Result<()> {
+    let old_arbiter = state.arbiter;
+    require!(
+        new_arbiter != old_arbiter && new_arbiter != state.seller && new_arbiter != state.buyer,
+        EscrowError::InvalidArbiter
+    );
+
+    state.arbiter = new_arbiter;
+    // Counts the change, as amendments and other state transitions do.
+    state.nonce = state
+        .nonce
+        .checked_add(1)
+        .ok_or(EscrowError::InvalidState)?;
+    // A replacement arbiter gets a fresh window on an open dispute.
+    if resolution_window > 0 {
+        let resolve_by = Clock::get()?
+            .unix_timestamp
+            .checked_add(resolution_window)
+            .ok_or(EscrowError::Overflow)?;
+        if state.status == EscrowStatus::Disputed {
+            state.resolve_by = resolve_by;
+        }
+        if state.is_milestone {
+            let schedule = milestone_schedule.ok_or(EscrowError::InvalidMilestone)?;
+            for milestone in schedule.milestones[..schedule.count as usize].iter_mut() {
+                if milestone.status == MilestoneStatus::Disputed {
+                    milestone.resolve_by = resolve_by;
+                }
+            }
+        }
+    }
+
+    emit!(ArbiterChanged {
+        escrow: escrow_key,
+        old_arbiter,
+        new_arbiter,
+        by_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::MilestonesCreated;
+use crate::state::{
+    EscrowState, EscrowStatus, Milestone, MilestoneSchedule, MilestoneStatus, MilestoneTerms,
+    MAX_MILESTONES,
+};
+
+// Both parties sign: the schedule decides when and how the seller gets paid.
+#[derive(Accounts)]
+pub struct CreateMilestones<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::Unauthorized,
+        constraint = seller.key() == escrow_state.seller @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        // Tranches are the only release schedule a milestone deal has.
+        constraint = escrow_state.vest_end == 0 && escrow_state.periods == 0 @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump,
+        space = MilestoneSchedule::space()
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_milestones(
+    ctx: Context<CreateMilestones>,
+    milestones: Vec<MilestoneTerms>,
+) -> Result<()> {
+    let count = milestones.len();
+    require!(
+        count > 0 && count <= MAX_MILESTONES,
+        EscrowError::InvalidMilestone
+    );
+
+    let state = &ctx.accounts.escrow_state;
+    // A flat arbiter fee cannot be charged against individual tranches.
+    require!(state.arbiter_fee == 0, EscrowError::InvalidMilestone);
+
+    let mut total: u64 = 0;
+    let now = Clock::get()?.unix_timestamp;
+    for terms in milestones.iter() {
+        require!(terms.amount > 0, EscrowError::InvalidMilestone);
+        require!(
+            terms.due_date == 0 || terms.due_date > now,
+            EscrowError::InvalidMilestone
+        );
+        total = total
+            .checked_add(terms.amount)
+            .ok_or(EscrowError::Overflow)?;
+    }
+    require!(total == state.amount, EscrowError::InvalidMilestone);
+
+    let schedule = &mut ctx.accounts.milestone_schedule;
+    schedule.escrow = state.key();
+    schedule.count = count as u8;
+    schedule.milestones = [Milestone::default(); MAX_MILESTONES];
+    for (slot, terms) in schedule.milestones.iter_mut().zip(milestones.iter()) {
+        *slot = Milestone {
+            amount: terms.amount,
+            description_hash: terms.description_hash,
+            due_date: terms.due_date,
+            status: MilestoneStatus::Pending,
+            ..Milestone::default()
+        };
+    }
+    schedule.bump = ctx.bumps.milestone_schedule;
+    ctx.accounts.escrow_state.is_milestone = true;
+
+    emit!(MilestonesCreated {
+        escrow: schedule.escrow,
+        count: schedule.count,
+        amount: total,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PanelCreated;
+use crate::state::{ArbiterPanel, EscrowState, EscrowStatus, PanelVote, MAX_PANEL_ARBITERS};
+use crate::utils::assert_active_arbiter;
+
+// Both parties sign: the panel replaces the arbiter they agreed on at initiate.
+#[derive(Accounts)]
+pub struct CreatePanel<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = buyer.key() == escrow_state.buyer @ EscrowError::Unauthorized,
+        constraint = seller.key() == escrow_state.seller @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        init,
+        payer = buyer,
+        seeds = [b"panel", escrow_state.key().as_ref()],
+        bump,
+        space = ArbiterPanel::space()
+    )]
+    pub arbiter_panel: Account<'info, ArbiterPanel>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_panel(
+    ctx: Context<CreatePanel>,
+    threshold: u8,
+    arbiters: Vec<Pubkey>,
+) -> Result<()> {
+    let count = arbiters.len();
+    require!(
+        count > 0 && count <= MAX_PANEL_ARBITERS,
+        EscrowError::InvalidPanel
+    );
+    require!(
+        threshold > 0 && threshold as usize <= count,
+        EscrowError::InvalidPanel
+    );
+
+    // Registry profiles of the panel members, in the same order as `arbiters`.
+    require!(
+        ctx.remaining_accounts.len() == count,
+        EscrowError::InvalidPanel
+    );
+
+    let state = &mut ctx.accounts.escrow_state;
+    // Panel members are not compensated through the vault.
+    require!(state.arbiter_fee == 0, EscrowError::InvalidPanel);
+    for (i, arbiter) in arbiters.iter().enumerate() {
+        require!(
+            *arbiter != state.seller && *arbiter != state.buyer,
+            EscrowError::InvalidArbiter
+        );
+        require!(!arbiters[..i].contains(arbiter), EscrowError::InvalidPanel);
+        assert_active_arbiter(&ctx.remaining_accounts[i], arbiter)?;
+    }
+
+    let panel = &mut ctx.accounts.arbiter_panel;
+    panel.escrow = state.key();
+    panel.threshold = threshold;
+    panel.arbiter_count = count as u8;
+    panel.arbiters = [Pubkey::default(); MAX_PANEL_ARBITERS];
+    panel.arbiters[..count].copy_from_slice(&arbiters);
+    panel.votes = [PanelVote::default(); MAX_PANEL_ARBITERS];
+    panel.votes_cast = 0;
+    panel.bump = ctx.bumps.arbiter_panel;
+
+    // The panel PDA cannot sign, so single-arbiter `resolve` is disabled for this deal.
+    state.arbiter = panel.key();
+
+    emit!(PanelCreated {
+        escrow: panel.escrow,
+        threshold,
+        arbiters,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterDeactivated;
+use crate::state::{ArbiterPool, ArbiterProfile, ArbiterStatus};
+use crate::utils::UNSTAKE_COOLDOWN;
+
+// The arbiter leaves the pool at once; the stake stays slashable for the cooldown.
+#[derive(Accounts)]
+pub struct DeactivateArbiter<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(mut, seeds = [b"arbiter_pool"], bump = arbiter_pool.bump)]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.unstake_at == 0 @ EscrowError::InvalidState,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_deactivate_arbiter(ctx: Context<DeactivateArbiter>) -> Result<()> {
+    let unstake_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(UNSTAKE_COOLDOWN)
+        .ok_or(EscrowError::Overflow)?;
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    ctx.accounts.arbiter_pool.remove(&profile.authority);
+    profile.status = ArbiterStatus::Unstaking;
+    profile.unstake_at = unstake_at;
+
+    emit!(ArbiterDeactivated {
+        arbiter: profile.authority,
+        unstake_at,
+    });
+
+    Ok(())
+}
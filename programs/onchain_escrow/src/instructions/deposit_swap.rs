@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::SwapDeposited;
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::deposit_transfer;
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct DepositSwap<'info> {
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.swap_mint != Pubkey::default() @ EscrowError::InvalidState,
+        constraint = !escrow_state.swap_deposited @ EscrowError::InvalidState,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        constraint = seller_ata.owner == seller.key() @ EscrowError::Unauthorized,
+        constraint = seller_ata.mint == escrow_state.swap_mint @ EscrowError::MintMismatch,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = escrow_state.swap_vault_ata,
+    )]
+    pub swap_vault_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_deposit_swap(ctx: Context<DepositSwap>, _deal_id: [u8; 16]) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= state.swap_by, EscrowError::DeadlinePassed);
+
+    let amount = state.swap_amount;
+    require!(
+        ctx.accounts.seller_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    deposit_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.seller_ata.to_account_info(),
+        ctx.accounts.swap_vault_ata.to_account_info(),
+        ctx.accounts.seller.to_account_info(),
+        amount,
+    )?;
+
+    state.swap_deposited = true;
+
+    emit!(SwapDeposited {
+        escrow: escrow_key,
+        amount,
+    });
+
+    Ok(())
+}
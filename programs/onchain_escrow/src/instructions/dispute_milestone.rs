@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::MilestoneDisputed;
+use crate::state::{
+    Config, EscrowState, EscrowStatus, MilestoneSchedule, MilestoneStatus, PanelVote,
+    MAX_PANEL_ARBITERS,
+};
+use crate::utils::ARBITER_ASSIGNMENT_DELAY_SLOTS;
+
+// Freezes one tranche for the arbiter; the other milestones keep flowing. A milestone
+// past its due date has been approved and can no longer be disputed. Deals without an
+// arbiter get one drawn from the pool, who then hears every milestone dispute.
+#[derive(Accounts)]
+pub struct DisputeMilestone<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = caller.key() == escrow_state.seller || caller.key() == escrow_state.buyer @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handle_dispute_milestone(ctx: Context<DisputeMilestone>, index: u8) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    if state.dispute_by > 0 {
+        require!(now <= state.dispute_by, EscrowError::DeadlinePassed);
+    }
+    let resolve_by = ctx.accounts.config.resolve_by(now)?;
+
+    let milestone = ctx
+        .accounts
+        .milestone_schedule
+        .get_mut(index)
+        .ok_or(EscrowError::InvalidMilestone)?;
+    require!(
+        milestone.status == MilestoneStatus::Pending,
+        EscrowError::InvalidMilestone
+    );
+    require!(!milestone.approval_lapsed(now), EscrowError::DeadlinePassed);
+    milestone.status = MilestoneStatus::Disputed;
+    milestone.resolve_by = resolve_by;
+    milestone.votes = [PanelVote::default(); MAX_PANEL_ARBITERS];
+
+    if state.arbiter == Pubkey::default() && state.assign_slot == 0 {
+        state.assign_slot = Clock::get()?
+            .slot
+            .checked_add(ARBITER_ASSIGNMENT_DELAY_SLOTS)
+            .ok_or(EscrowError::Overflow)?;
+    }
+
+    emit!(MilestoneDisputed {
+        escrow: escrow_key,
+        index,
+        by: ctx.accounts.caller.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DealFunded;
+use crate::state::{EscrowState, EscrowStatus, RefundPolicy};
+use crate::utils::{deposit_transfer, vault_authority_address};
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct Fund<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+        constraint = escrow_state.amount > 0 @ EscrowError::InsufficientFunds,
+        constraint = escrow_state.collateral_ready() @ EscrowError::CollateralNotPosted,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        constraint = buyer_ata.owner == buyer.key() @ EscrowError::Unauthorized,
+        constraint = buyer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_fund(ctx: Context<Fund>, _deal_id: [u8; 16]) -> Result<()> {
+    let expected_vault = ctx.accounts.vault_authority_key()?;
+    let state = &mut ctx.accounts.escrow_state;
+    require_keys_eq!(
+        ctx.accounts.vault_ata.owner,
+        expected_vault,
+        EscrowError::VaultOwnerMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !state.is_swap() || now <= state.swap_by,
+        EscrowError::DeadlinePassed
+    );
+    if state.fund_by > 0 {
+        require!(now <= state.fund_by, EscrowError::DeadlinePassed);
+    }
+
+    let amount = state.amount;
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.buyer_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    deposit_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_ata.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        amount,
+    )?;
+
+    state.status = EscrowStatus::Funded;
+    state.funded_amount = amount;
+    state.funder = state.buyer;
+    state.refund_policy = RefundPolicy::Buyer;
+
+    emit!(DealFunded {
+        buyer: state.buyer,
+        funder: state.funder,
+        amount,
+    });
+
+    Ok(())
+}
+
+impl<'info> Fund<'info> {
+    fn vault_authority_key(&self) -> Result<Pubkey> {
+        vault_authority_address(&self.escrow_state.key(), self.escrow_state.bump)
+    }
+}
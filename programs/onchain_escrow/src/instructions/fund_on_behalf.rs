@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DealFunded;
+use crate::state::{EscrowState, EscrowStatus, RefundPolicy};
+use crate::utils::{deposit_transfer, vault_authority_address};
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct FundOnBehalf<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+        constraint = escrow_state.amount > 0 @ EscrowError::InsufficientFunds,
+        constraint = escrow_state.collateral_ready() @ EscrowError::CollateralNotPosted,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        constraint = funder_ata.owner == funder.key() @ EscrowError::Unauthorized,
+        constraint = funder_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub funder_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_fund_on_behalf(
+    ctx: Context<FundOnBehalf>,
+    _deal_id: [u8; 16],
+    refund_policy: RefundPolicy,
+) -> Result<()> {
+    let expected_vault = vault_authority_address(
+        &ctx.accounts.escrow_state.key(),
+        ctx.accounts.escrow_state.bump,
+    )?;
+    require_keys_eq!(
+        ctx.accounts.vault_ata.owner,
+        expected_vault,
+        EscrowError::VaultOwnerMismatch
+    );
+
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !state.is_swap() || now <= state.swap_by,
+        EscrowError::DeadlinePassed
+    );
+    if state.fund_by > 0 {
+        require!(now <= state.fund_by, EscrowError::DeadlinePassed);
+    }
+
+    let amount = state.amount;
+    require!(
+        ctx.accounts.funder_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    deposit_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.funder_ata.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.funder.to_account_info(),
+        amount,
+    )?;
+
+    state.status = EscrowStatus::Funded;
+    state.funded_amount = amount;
+    state.funder = ctx.accounts.funder.key();
+    state.refund_policy = refund_policy;
+    // Refunds follow the money back to the funder; the buyer can no longer redirect them.
+    if refund_policy == RefundPolicy::Funder {
+        state.refund_to = state.funder;
+    }
+
+    emit!(DealFunded {
+        buyer: state.buyer,
+        funder: state.funder,
+        amount,
+    });
+
+    Ok(())
+}
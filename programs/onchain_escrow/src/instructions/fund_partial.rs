@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{DealFunded, DealPartiallyFunded};
+use crate::state::{EscrowState, EscrowStatus, RefundPolicy};
+use crate::utils::{deposit_transfer, vault_authority_address};
+
+// Installments come from the buyer's own token account; a third party funds the whole
+// deal at once through `fund_on_behalf`.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct FundPartial<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::PartiallyFunded) @ EscrowError::InvalidState,
+        constraint = escrow_state.collateral_ready() @ EscrowError::CollateralNotPosted,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        constraint = buyer_ata.owner == buyer.key() @ EscrowError::Unauthorized,
+        constraint = buyer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_fund_partial(
+    ctx: Context<FundPartial>,
+    _deal_id: [u8; 16],
+    deposit: u64,
+) -> Result<()> {
+    let expected_vault = vault_authority_address(
+        &ctx.accounts.escrow_state.key(),
+        ctx.accounts.escrow_state.bump,
+    )?;
+    require_keys_eq!(
+        ctx.accounts.vault_ata.owner,
+        expected_vault,
+        EscrowError::VaultOwnerMismatch
+    );
+
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !state.is_swap() || now <= state.swap_by,
+        EscrowError::DeadlinePassed
+    );
+    if state.fund_by > 0 {
+        require!(now <= state.fund_by, EscrowError::DeadlinePassed);
+    }
+
+    let remaining = state
+        .amount
+        .checked_sub(state.funded_amount)
+        .ok_or(EscrowError::Overflow)?;
+    require!(
+        deposit > 0 && deposit <= remaining,
+        EscrowError::InsufficientFunds
+    );
+    require!(
+        ctx.accounts.buyer_ata.amount >= deposit,
+        EscrowError::InsufficientFunds
+    );
+
+    deposit_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.buyer_ata.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        deposit,
+    )?;
+
+    state.funded_amount += deposit;
+    state.funder = state.buyer;
+    state.refund_policy = RefundPolicy::Buyer;
+
+    emit!(DealPartiallyFunded {
+        escrow: escrow_state_key,
+        deposit,
+        funded_amount: state.funded_amount,
+        amount: state.amount,
+    });
+
+    if state.funded_amount == state.amount {
+        state.status = EscrowStatus::Funded;
+
+        emit!(DealFunded {
+            buyer: state.buyer,
+            funder: state.funder,
+            amount: state.amount,
+        });
+    } else {
+        state.status = EscrowStatus::PartiallyFunded;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::EscrowError;
+use crate::events::ConfigUpdated;
+use crate::program::OnchainEscrowProgram;
+use crate::state::{ArbiterPool, Config, TimeoutFallback};
+
+// Only the upgrade authority may claim the admin role, so the config cannot be front-run.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ EscrowError::NotUpgradeAuthority,
+    )]
+    pub program: Program<'info, OnchainEscrowProgram>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"config"],
+        bump,
+        space = Config::space()
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"arbiter_pool"],
+        bump,
+        space = ArbiterPool::space()
+    )]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    pub stake_mint: Account<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_config(
+    ctx: Context<InitializeConfig>,
+    treasury: Pubkey,
+    min_stake: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.treasury = treasury;
+    config.stake_mint = ctx.accounts.stake_mint.key();
+    config.min_stake = min_stake;
+    config.resolution_window = 0;
+    config.timeout_fallback = TimeoutFallback::RefundBuyer;
+    config.backup_arbiter = Pubkey::default();
+    config.max_arbiter_fee_bps = 0;
+    config.max_referral_bps = 0;
+    config.bump = ctx.bumps.config;
+    config._reserved = [0; 32];
+
+    let pool = &mut ctx.accounts.arbiter_pool;
+    pool.arbiters = Vec::new();
+    pool.bump = ctx.bumps.arbiter_pool;
+
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        treasury,
+        min_stake,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DealInitiated;
+use crate::state::{
+    ArbiterFeeTerms, ArbiterProfile, ArbiterStatus, Config, DisputeBondTerms, EscrowState,
+    EscrowStatus, LatePenaltyTerms, RefundPolicy, SubscriptionTerms, VestingTerms,
+};
+use crate::utils::{bps_of, validate_deadlines, BPS_DENOMINATOR};
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct Initiate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: seller address
+    pub seller: UncheckedAccount<'info>,
+    /// CHECK: buyer address
+    pub buyer: UncheckedAccount<'info>,
+    /// CHECK: owner of the token account receiving the seller's proceeds; defaults to the seller.
+    /// Any other address needs the seller's signature.
+    pub payout_to: Option<UncheckedAccount<'info>>,
+    /// CHECK: marketplace partner credited with the deal; receives part of the protocol fee
+    pub referrer: Option<UncheckedAccount<'info>>,
+    /// CHECK: arbiter authority, validated through its registry profile.
+    /// Pass the default key to have one assigned from the pool when a dispute is opened.
+    pub arbiter: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // deal_id as [u8; 16] converted to slice
+        ],
+        bump,
+        space = EscrowState::space()
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA derived for vault authority
+    #[account(
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_initiate(
+    ctx: Context<Initiate>,
+    amount: u64,
+    fee_bps: u16,
+    dispute_by: i64,
+    _deal_id: [u8; 16], // Still in instruction signature but not used in PDA seeds
+    arbiter_fee_terms: ArbiterFeeTerms,
+    dispute_bond: DisputeBondTerms,
+    fund_by: i64,
+    vesting: VestingTerms,
+    subscription: SubscriptionTerms,
+    collateral: u64,
+    late_penalty: LatePenaltyTerms,
+    cancel_fee_bps: u16,
+    referral_bps: u16,
+) -> Result<()> {
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        fee_bps <= BPS_DENOMINATOR
+            && late_penalty.max_bps <= BPS_DENOMINATOR
+            && cancel_fee_bps <= BPS_DENOMINATOR,
+        EscrowError::FeeTooHigh
+    );
+    require!(
+        referral_bps <= ctx.accounts.config.max_referral_bps,
+        EscrowError::FeeTooHigh
+    );
+    require!(
+        vesting.end == 0 || vesting.end > vesting.start,
+        EscrowError::InvalidVesting
+    );
+    let period_amount = if subscription.periods > 0 {
+        // u64::is_multiple_of needs Rust 1.87, newer than the SBF toolchain.
+        #[allow(clippy::manual_is_multiple_of)]
+        let whole_periods = amount % subscription.periods as u64 == 0;
+        require!(
+            vesting.end == 0 && subscription.period_secs > 0 && whole_periods,
+            EscrowError::InvalidSubscription
+        );
+        amount / subscription.periods as u64
+    } else {
+        0
+    };
+
+    // The arbiter fee is either flat or a share of the deal, capped by config either way.
+    require!(
+        arbiter_fee_terms.bps == 0 || arbiter_fee_terms.flat == 0,
+        EscrowError::InvalidArbiterFee
+    );
+    let arbiter_fee = if arbiter_fee_terms.bps > 0 {
+        bps_of(amount, arbiter_fee_terms.bps)?
+    } else {
+        arbiter_fee_terms.flat
+    };
+    require!(
+        arbiter_fee <= bps_of(amount, ctx.accounts.config.max_arbiter_fee_bps)?,
+        EscrowError::FeeTooHigh
+    );
+    if let Some(payout_to) = &ctx.accounts.payout_to {
+        require!(
+            payout_to.key() == ctx.accounts.seller.key() || ctx.accounts.seller.is_signer,
+            EscrowError::Unauthorized
+        );
+    }
+    let arbiter = ctx.accounts.arbiter.key();
+    if arbiter != Pubkey::default() {
+        require!(
+            ctx.accounts.arbiter_profile.is_some(),
+            EscrowError::InvalidArbiter
+        );
+        require!(
+            arbiter != ctx.accounts.seller.key() && arbiter != ctx.accounts.buyer.key(),
+            EscrowError::InvalidArbiter
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    validate_deadlines(now, &[dispute_by, fund_by, late_penalty.deliver_by])?;
+    let vault_bump = ctx.bumps.vault_authority;
+
+    let state = &mut ctx.accounts.escrow_state;
+    state.version = EscrowState::VERSION;
+    state.bump = vault_bump;
+    state.seller = ctx.accounts.seller.key();
+    state.payout_to = ctx
+        .accounts
+        .payout_to
+        .as_ref()
+        .map_or(state.seller, |payout_to| payout_to.key());
+    state.buyer = ctx.accounts.buyer.key();
+    state.refund_to = state.buyer;
+    state.funder = Pubkey::default();
+    state.refund_policy = RefundPolicy::Buyer;
+    state.mint = ctx.accounts.mint.key();
+    state.amount = amount;
+    state.fee_bps = fee_bps;
+    state.vault_ata = ctx.accounts.vault_ata.key();
+    state.arbiter = ctx.accounts.arbiter.key();
+    state.status = EscrowStatus::Init;
+    state.nonce = 0;
+    state.created_at = now;
+    state.dispute_by = dispute_by;
+    state.fund_by = fund_by;
+    state.funded_amount = 0;
+    state.split_buyer_bps = 0;
+    state.resolve_by = 0;
+    state.arbiter_fee = arbiter_fee;
+    state.arbiter_fee_bps = arbiter_fee_terms.bps;
+    state.arbiter_fee_policy = arbiter_fee_terms.policy;
+    state.arbiter_fee_earned = false;
+    state.dispute_bond = dispute_bond.amount;
+    state.bond_forfeit_to = dispute_bond.forfeit_to;
+    state.bond_posted = 0;
+    state.bond_poster = Pubkey::default();
+    state.vest_start = vesting.start;
+    state.vest_end = vesting.end;
+    state.vest_total = amount;
+    state.vest_withdrawn = 0;
+    state.vest_frozen_at = 0;
+    state.vest_claimable = 0;
+    state.period_start = subscription.start;
+    state.period_secs = subscription.period_secs;
+    state.period_amount = period_amount;
+    state.periods = subscription.periods;
+    state.periods_claimed = 0;
+    state.swap_mint = Pubkey::default();
+    state.swap_vault_ata = Pubkey::default();
+    state.swap_amount = 0;
+    state.swap_by = 0;
+    state.swap_deposited = false;
+    state.collateral = collateral;
+    state.collateral_posted = 0;
+    state.deliver_by = late_penalty.deliver_by;
+    state.delivered_at = 0;
+    state.disputed_at = 0;
+    state.penalty_bps_per_day = late_penalty.bps_per_day;
+    state.penalty_max_bps = late_penalty.max_bps;
+    state.cancel_fee_bps = cancel_fee_bps;
+    state.has_payout_split = false;
+    state.is_milestone = false;
+    state.referrer = ctx
+        .accounts
+        .referrer
+        .as_ref()
+        .map_or(Pubkey::default(), |referrer| referrer.key());
+    state.referral_bps = if state.referrer == Pubkey::default() {
+        0
+    } else {
+        referral_bps
+    };
+    state._reserved = [0; 32];
+
+    emit!(DealInitiated {
+        seller: state.seller,
+        buyer: state.buyer,
+        mint: state.mint,
+        amount,
+    });
+
+    Ok(())
+}
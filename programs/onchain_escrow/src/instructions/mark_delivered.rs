@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::DeliveryMarked;
+use crate::state::{EscrowState, EscrowStatus};
+
+// Stops the late-penalty clock; the buyer can still dispute the delivery itself. Once a
+// dispute is open its start already stops the clock, so a later claim is not accepted.
+#[derive(Accounts)]
+pub struct MarkDelivered<'info> {
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+        constraint = escrow_state.delivered_at == 0 @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+}
+
+pub fn handle_mark_delivered(ctx: Context<MarkDelivered>) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    state.delivered_at = Clock::get()?.unix_timestamp;
+
+    emit!(DeliveryMarked {
+        escrow: escrow_key,
+        delivered_at: state.delivered_at,
+        deliver_by: state.deliver_by,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::EscrowError;
+use crate::events::EscrowMigrated;
+use crate::state::{Config, EscrowState, EscrowStateV1, EscrowStatus};
+
+// Permissionless: the upgrade is deterministic, so anyone may pay to migrate a deal.
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: version 1 escrow; owner, discriminator and layout are checked in the handler
+    #[account(mut, owner = crate::ID @ EscrowError::InvalidState)]
+    pub escrow_state: UncheckedAccount<'info>,
+    // Migrated deals pay out through instructions that read the config, so it must exist.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_escrow(ctx: Context<MigrateEscrow>) -> Result<()> {
+    let escrow_info = ctx.accounts.escrow_state.to_account_info();
+    let v1 = {
+        let data = escrow_info.try_borrow_data()?;
+        require!(
+            data.len() == EscrowStateV1::LEN && data[..8] == *EscrowState::DISCRIMINATOR,
+            EscrowError::InvalidState
+        );
+        EscrowStateV1::deserialize(&mut &data[8..])?
+    };
+    require!(
+        v1.version == EscrowStateV1::VERSION,
+        EscrowError::InvalidState
+    );
+
+    let new_len = EscrowState::space();
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(escrow_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: escrow_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    escrow_info.resize(new_len)?;
+
+    let mut state = EscrowState::from(v1);
+    // Version 1 disputes have no deadline and their arbiters are usually unregistered, so
+    // `resolve` is out of reach for them. Start the timeout clock so `timeout_dispute` can
+    // apply the fallback; without a configured window it may do so right away.
+    if state.status == EscrowStatus::Disputed {
+        let now = Clock::get()?.unix_timestamp;
+        state.resolve_by = match ctx.accounts.config.resolve_by(now)? {
+            0 => now,
+            resolve_by => resolve_by,
+        };
+    }
+    let mut data = escrow_info.try_borrow_mut_data()?;
+    state.try_serialize(&mut &mut data[..])?;
+
+    emit!(EscrowMigrated {
+        escrow: escrow_info.key(),
+        from_version: v1.version,
+        to_version: state.version,
+    });
+
+    Ok(())
+}
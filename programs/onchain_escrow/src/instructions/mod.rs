@@ -0,0 +1,101 @@
+pub mod amend;
+pub mod assign_arbiter;
+pub mod buyer_cancel;
+pub mod cancel_funding;
+pub mod cancel_subscription;
+pub mod change_arbiter;
+pub mod claim_periods;
+pub mod create_milestones;
+pub mod create_panel;
+pub mod deactivate_arbiter;
+pub mod deposit_swap;
+pub mod dispute_milestone;
+pub mod execute_swap;
+pub mod fund;
+pub mod fund_on_behalf;
+pub mod fund_partial;
+pub mod initialize_config;
+pub mod initiate;
+pub mod mark_delivered;
+pub mod migrate_escrow;
+pub mod mutual_settle;
+pub mod open_dispute;
+pub mod partial_refund;
+pub mod post_collateral;
+pub mod reclaim_swap_deposit;
+pub mod refund;
+pub mod register_arbiter;
+pub mod release;
+pub mod release_milestone;
+pub mod resolve;
+pub mod resolve_milestone;
+pub mod set_arbiter_status;
+pub mod set_payout_address;
+pub mod set_payout_split;
+pub mod set_refund_address;
+pub mod settle;
+pub mod settle_dispute_bond;
+pub mod settle_milestone;
+pub mod setup_swap;
+pub mod slash_arbiter;
+pub mod timeout_dispute;
+pub mod timeout_milestone;
+pub mod update_config;
+pub mod update_dispute_policy;
+pub mod update_fee_config;
+pub mod vote;
+pub mod vote_milestone;
+pub mod withdraw_collateral;
+pub mod withdraw_stake;
+pub mod withdraw_vested;
+
+pub use amend::*;
+pub use assign_arbiter::*;
+pub use buyer_cancel::*;
+pub use cancel_funding::*;
+pub use cancel_subscription::*;
+pub use change_arbiter::*;
+pub use claim_periods::*;
+pub use create_milestones::*;
+pub use create_panel::*;
+pub use deactivate_arbiter::*;
+pub use deposit_swap::*;
+pub use dispute_milestone::*;
+pub use execute_swap::*;
+pub use fund::*;
+pub use fund_on_behalf::*;
+pub use fund_partial::*;
+pub use initialize_config::*;
+pub use initiate::*;
+pub use mark_delivered::*;
+pub use migrate_escrow::*;
+pub use mutual_settle::*;
+pub use open_dispute::*;
+pub use partial_refund::*;
+pub use post_collateral::*;
+pub use reclaim_swap_deposit::*;
+pub use refund::*;
+pub use register_arbiter::*;
+pub use release::*;
+pub use release_milestone::*;
+pub use resolve::*;
+pub use resolve_milestone::*;
+pub use set_arbiter_status::*;
+pub use set_payout_address::*;
+pub use set_payout_split::*;
+pub use set_refund_address::*;
+pub use settle::*;
+pub use settle_dispute_bond::*;
+pub use settle_milestone::*;
+pub use setup_swap::*;
+pub use slash_arbiter::*;
+pub use timeout_dispute::*;
+pub use timeout_milestone::*;
+pub use update_config::*;
+pub use update_dispute_policy::*;
+pub use update_fee_config::*;
+pub use vote::*;
+pub use vote_milestone::*;
+pub use withdraw_collateral::*;
+pub use withdraw_stake::*;
+pub use withdraw_vested::*;
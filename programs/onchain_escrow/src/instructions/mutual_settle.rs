@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{CollateralSettled, DealMutuallySettled};
+use crate::state::{Config, EscrowState, EscrowStatus, PayoutSplit};
+use crate::utils::{
+    settle_payout, split_amount, vault_signer_seeds, PayoutAccounts, VERDICT_SPLIT,
+};
+
+// Both parties sign the same transaction; a relayer can collect their signatures
+// offline and submit it as fee payer.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct MutualSettle<'info> {
+    pub buyer: Signer<'info>,
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    /// Pays rent for any recipient ATA that has to be created.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: seller payout owner recorded in escrow_state
+    #[account(address = escrow_state.payout_to @ EscrowError::Unauthorized)]
+    pub seller_payout: UncheckedAccount<'info>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        associated_token::mint = mint,
+        associated_token::authority = seller_payout
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    // Required when the deal has a payout split; recipient token accounts follow in
+    // `remaining_accounts`, in table order.
+    #[account(
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Option<Account<'info, PayoutSplit>>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Protocol fee recipients, required only when the respective share is non-zero.
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = referrer_ata.owner == escrow_state.referrer @ EscrowError::Unauthorized,
+        constraint = referrer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub referrer_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_mutual_settle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MutualSettle<'info>>,
+    _deal_id: [u8; 16],
+    buyer_bps: u16,
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let amount = state.amount;
+    let bond = state.bond_posted;
+    let collateral = state.collateral_posted;
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.vault_ata.amount
+            >= amount
+                .checked_add(bond)
+                .and_then(|v| v.checked_add(collateral))
+                .ok_or(EscrowError::Overflow)?,
+        EscrowError::InsufficientFunds
+    );
+    let (buyer_amount, seller_amount) = split_amount(amount, buyer_bps)?;
+
+    // No verdict was reached, so a posted dispute bond simply goes back to its poster and
+    // the seller's collateral goes back to the seller.
+    let (buyer_bond, seller_bond) = if state.bond_poster == state.buyer {
+        (bond, 0)
+    } else {
+        (0, bond)
+    };
+    state.bond_posted = 0;
+    state.collateral_posted = 0;
+
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: Some(ctx.accounts.buyer_ata.to_account_info()),
+        seller_ata: Some(ctx.accounts.seller_ata.to_account_info()),
+        arbiter_ata: None,
+        treasury_ata: ctx.accounts.treasury_ata.as_ref(),
+        referrer_ata: ctx.accounts.referrer_ata.as_ref(),
+        payout_split: ctx.accounts.payout_split.as_ref(),
+        split_recipients: ctx.remaining_accounts,
+    };
+    let payout = settle_payout(
+        &accounts,
+        state,
+        escrow_state_key,
+        buyer_amount,
+        seller_amount,
+        false,
+        (buyer_bond, seller_bond + collateral),
+    )?;
+
+    state.amount = 0;
+    state.status = EscrowStatus::Settled;
+    state._reserved[0] = VERDICT_SPLIT;
+    state.split_buyer_bps = buyer_bps;
+    state.nonce = state
+        .nonce
+        .checked_add(1)
+        .ok_or(EscrowError::InvalidState)?;
+
+    emit!(DealMutuallySettled {
+        escrow: escrow_state_key,
+        buyer_bps,
+        buyer_amount: payout.buyer_amount,
+        seller_amount: payout.seller_amount,
+        bond_returned: bond,
+    });
+    if collateral > 0 {
+        emit!(CollateralSettled {
+            escrow: escrow_state_key,
+            buyer_amount: 0,
+            seller_amount: collateral,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{DealDisputed, DisputeBondPosted};
+use crate::state::{Config, EscrowState, EscrowStatus};
+use crate::utils::{deposit_transfer, ARBITER_ASSIGNMENT_DELAY_SLOTS};
+
+// Deals without an arbiter get one drawn from the pool by `assign_arbiter`, from the hash
+// of a slot that does not exist yet when the dispute is opened.
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Bond accounts, required only when the deal sets a dispute bond.
+    #[account(
+        mut,
+        constraint = caller_ata.owner == caller.key() @ EscrowError::Unauthorized,
+        constraint = caller_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub caller_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+    )]
+    pub vault_ata: Option<Account<'info, TokenAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handle_open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+
+    require!(
+        ctx.accounts.caller.key() == state.seller || ctx.accounts.caller.key() == state.buyer,
+        EscrowError::Unauthorized
+    );
+
+    if state.dispute_bond > 0 {
+        let (Some(caller_ata), Some(vault_ata), Some(token_program)) = (
+            ctx.accounts.caller_ata.as_ref(),
+            ctx.accounts.vault_ata.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EscrowError::MissingBondAccounts);
+        };
+        let bond = state.dispute_bond;
+        require!(caller_ata.amount >= bond, EscrowError::InsufficientFunds);
+
+        deposit_transfer(
+            token_program.to_account_info(),
+            caller_ata.to_account_info(),
+            vault_ata.to_account_info(),
+            ctx.accounts.caller.to_account_info(),
+            bond,
+        )?;
+
+        state.bond_posted = bond;
+        state.bond_poster = ctx.accounts.caller.key();
+
+        emit!(DisputeBondPosted {
+            escrow: escrow_key,
+            by: state.bond_poster,
+            amount: bond,
+        });
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if state.dispute_by > 0 {
+        require!(now <= state.dispute_by, EscrowError::DeadlinePassed);
+    }
+    state.freeze_vesting(now)?;
+
+    if state.arbiter == Pubkey::default() {
+        state.assign_slot = Clock::get()?
+            .slot
+            .checked_add(ARBITER_ASSIGNMENT_DELAY_SLOTS)
+            .ok_or(EscrowError::Overflow)?;
+    }
+
+    state.resolve_by = ctx.accounts.config.resolve_by(now)?;
+    state.disputed_at = now;
+    state.status = EscrowStatus::Disputed;
+
+    emit!(DealDisputed {
+        by: ctx.accounts.caller.key()
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DealPartiallyRefunded;
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::{bps_of, vault_signer_seeds, vault_transfer};
+
+// Seller-granted price adjustment; the rest of the deal continues as before.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct PartialRefund<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = escrow_state.periods == 0 @ EscrowError::InvalidSubscription,
+        // Milestone tranches are fixed to `amount`.
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_partial_refund(
+    ctx: Context<PartialRefund>,
+    _deal_id: [u8; 16],
+    refund_amount: u64,
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    // A full refund goes through the dispute path; something must be left to release.
+    require!(
+        refund_amount > 0 && refund_amount < state.amount,
+        EscrowError::InsufficientFunds
+    );
+    let remaining = state.amount - refund_amount;
+    // A fee set as a share of the deal follows the smaller amount, as under `amend`.
+    let arbiter_fee = if state.arbiter_fee_bps > 0 {
+        bps_of(remaining, state.arbiter_fee_bps)?
+    } else {
+        state.arbiter_fee
+    };
+    require!(arbiter_fee <= remaining, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.vault_ata.amount >= refund_amount,
+        EscrowError::InsufficientFunds
+    );
+
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.buyer_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        refund_amount,
+    )?;
+
+    state.amount = remaining;
+    state.arbiter_fee = arbiter_fee;
+    state.vest_total = state.vest_total.saturating_sub(refund_amount);
+
+    emit!(DealPartiallyRefunded {
+        escrow: escrow_state_key,
+        refunded: refund_amount,
+        remaining: state.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::SwapDepositReclaimed;
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::{vault_signer_seeds, vault_transfer};
+
+// After `swap_by`, each party takes back its own leg, whether or not the other leg was
+// ever deposited or has already been reclaimed.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct ReclaimSellerDeposit<'info> {
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.swap_deposited @ EscrowError::InvalidState,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded | EscrowStatus::Cancelled) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vaults
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.swap_vault_ata,
+        constraint = swap_vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub swap_vault_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = seller_ata.owner == seller.key() @ EscrowError::Unauthorized,
+        constraint = seller_ata.mint == escrow_state.swap_mint @ EscrowError::MintMismatch,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_reclaim_seller_deposit(
+    ctx: Context<ReclaimSellerDeposit>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now > state.swap_by, EscrowError::DeadlineNotReached);
+
+    let amount = state.swap_amount;
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.swap_vault_ata.to_account_info(),
+        ctx.accounts.seller_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        amount,
+    )?;
+
+    state.swap_deposited = false;
+    if state.status == EscrowStatus::Init {
+        state.status = EscrowStatus::Cancelled;
+    }
+
+    emit!(SwapDepositReclaimed {
+        escrow: escrow_state_key,
+        by: ctx.accounts.seller.key(),
+        mint: state.swap_mint,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct ReclaimBuyerDeposit<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.swap_mint != Pubkey::default() @ EscrowError::InvalidState,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vaults
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_reclaim_buyer_deposit(
+    ctx: Context<ReclaimBuyerDeposit>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now > state.swap_by, EscrowError::DeadlineNotReached);
+
+    let amount = state.amount;
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.buyer_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        amount,
+    )?;
+
+    state.amount = 0;
+    state.status = EscrowStatus::Cancelled;
+
+    emit!(SwapDepositReclaimed {
+        escrow: escrow_state_key,
+        by: ctx.accounts.buyer.key(),
+        mint: state.mint,
+        amount,
+    });
+
+    Ok(())
+}
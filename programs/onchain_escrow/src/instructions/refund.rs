@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{CollateralSettled, DealRefunded};
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::{settle_payout, vault_signer_seeds, PayoutAccounts, VERDICT_REFUND};
+
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct Refund<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Resolved @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    /// Pays rent if `buyer_ata` has to be created.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = arbiter_ata.owner == escrow_state.arbiter @ EscrowError::InvalidArbiter,
+        constraint = arbiter_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub arbiter_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_refund(ctx: Context<Refund>, _deal_id: [u8; 16]) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    require!(
+        state._reserved[0] == VERDICT_REFUND,
+        EscrowError::InvalidState
+    );
+
+    let amount = state.amount;
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.vault_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+
+    // Nothing goes to the seller side on a refund, so no fee or split accounts are needed.
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: Some(ctx.accounts.buyer_ata.to_account_info()),
+        seller_ata: None,
+        arbiter_ata: ctx.accounts.arbiter_ata.as_ref(),
+        treasury_ata: None,
+        referrer_ata: None,
+        payout_split: None,
+        split_recipients: &[],
+    };
+    let payout = settle_payout(&accounts, state, escrow_state_key, amount, 0, true, (0, 0))?;
+
+    state.amount = 0;
+    state.status = EscrowStatus::Refunded;
+
+    emit!(DealRefunded {
+        amount: payout.buyer_amount,
+        arbiter_fee: payout.arbiter_fee,
+    });
+    let (buyer_collateral, _) = payout.collateral;
+    if buyer_collateral > 0 {
+        emit!(CollateralSettled {
+            escrow: escrow_state_key,
+            buyer_amount: buyer_collateral,
+            seller_amount: 0,
+        });
+    }
+
+    Ok(())
+}
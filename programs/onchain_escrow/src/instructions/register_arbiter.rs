@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterRegistered;
+use crate::state::{ArbiterPool, ArbiterProfile, ArbiterStatus, Config};
+use crate::utils::deposit_transfer;
+
+#[derive(Accounts)]
+pub struct RegisterArbiter<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"arbiter_pool"], bump = arbiter_pool.bump)]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    #[account(
+        init,
+        payer = arbiter,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump,
+        space = ArbiterProfile::space()
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+    #[account(address = config.stake_mint @ EscrowError::MintMismatch)]
+    pub stake_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = arbiter_ata.owner == arbiter.key() @ EscrowError::Unauthorized,
+        constraint = arbiter_ata.mint == stake_mint.key() @ EscrowError::MintMismatch,
+    )]
+    pub arbiter_ata: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = arbiter,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbiter_profile
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_register_arbiter(ctx: Context<RegisterArbiter>, stake: u64) -> Result<()> {
+    require!(
+        stake > 0 && stake >= ctx.accounts.config.min_stake,
+        EscrowError::InsufficientStake
+    );
+    require!(
+        ctx.accounts.arbiter_ata.amount >= stake,
+        EscrowError::InsufficientFunds
+    );
+
+    deposit_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.arbiter_ata.to_account_info(),
+        ctx.accounts.stake_vault.to_account_info(),
+        ctx.accounts.arbiter.to_account_info(),
+        stake,
+    )?;
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    profile.authority = ctx.accounts.arbiter.key();
+    profile.stake_vault = ctx.accounts.stake_vault.key();
+    profile.staked_amount = stake;
+    profile.slashed_amount = 0;
+    profile.status = ArbiterStatus::Active;
+    profile.cases_handled = 0;
+    profile.cases_overturned = 0;
+    profile.registered_at = Clock::get()?.unix_timestamp;
+    profile.unstake_at = 0;
+    profile.bump = ctx.bumps.arbiter_profile;
+
+    require!(
+        ctx.accounts.arbiter_pool.add(profile.authority),
+        EscrowError::ArbiterPoolFull
+    );
+
+    emit!(ArbiterRegistered {
+        arbiter: profile.authority,
+        stake,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::MilestoneReleased;
+use crate::state::{
+    Config, EscrowState, EscrowStatus, MilestoneSchedule, MilestoneStatus, PayoutSplit,
+};
+use crate::utils::{settle_payout, vault_signer_seeds, PayoutAccounts};
+
+// The buyer approves a single tranche; the vault keeps the rest in `amount`. Once an
+// undisputed milestone is past its due date, anyone may release it.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: seller payout owner recorded in escrow_state
+    #[account(address = escrow_state.payout_to @ EscrowError::Unauthorized)]
+    pub seller_payout: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = seller_payout
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    // Required when the deal has a payout split; recipient token accounts follow in
+    // `remaining_accounts`, in table order.
+    #[account(
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Option<Account<'info, PayoutSplit>>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Protocol fee recipients, required only when the respective share is non-zero.
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = referrer_ata.owner == escrow_state.referrer @ EscrowError::Unauthorized,
+        constraint = referrer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub referrer_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_release_milestone<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleaseMilestone<'info>>,
+    _deal_id: [u8; 16],
+    index: u8,
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let milestone = ctx
+        .accounts
+        .milestone_schedule
+        .get_mut(index)
+        .ok_or(EscrowError::InvalidMilestone)?;
+    require!(
+        milestone.status == MilestoneStatus::Pending,
+        EscrowError::InvalidMilestone
+    );
+    if ctx.accounts.caller.key() != ctx.accounts.escrow_state.buyer {
+        require!(
+            milestone.approval_lapsed(Clock::get()?.unix_timestamp),
+            EscrowError::Unauthorized
+        );
+    }
+    let tranche = milestone.amount;
+    require!(
+        ctx.accounts.vault_ata.amount >= tranche,
+        EscrowError::InsufficientFunds
+    );
+
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: None,
+        seller_ata: Some(ctx.accounts.seller_ata.to_account_info()),
+        arbiter_ata: None,
+        treasury_ata: ctx.accounts.treasury_ata.as_ref(),
+        referrer_ata: ctx.accounts.referrer_ata.as_ref(),
+        payout_split: ctx.accounts.payout_split.as_ref(),
+        split_recipients: ctx.remaining_accounts,
+    };
+    let state = &mut ctx.accounts.escrow_state;
+    settle_payout(
+        &accounts,
+        state,
+        escrow_state_key,
+        0,
+        tranche,
+        false,
+        (0, 0),
+    )?;
+    milestone.status = MilestoneStatus::Released;
+
+    state.amount = state
+        .amount
+        .checked_sub(tranche)
+        .ok_or(EscrowError::Overflow)?;
+    if state.amount == 0 {
+        state.status = EscrowStatus::Released;
+    }
+
+    emit!(MilestoneReleased {
+        escrow: escrow_state_key,
+        index,
+        amount: tranche,
+        remaining: state.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::DealResolved;
+use crate::state::{ArbiterProfile, ArbiterStatus, EscrowState, EscrowStatus};
+use crate::utils::{verdict_buyer_bps, VERDICT_REFUND, VERDICT_RELEASE};
+
+#[derive(Accounts)]
+pub struct Resolve<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.arbiter == arbiter.key() @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_resolve(ctx: Context<Resolve>, verdict: u8) -> Result<()> {
+    require!(
+        verdict == VERDICT_RELEASE || verdict == VERDICT_REFUND,
+        EscrowError::InvalidState
+    );
+
+    let state = &mut ctx.accounts.escrow_state;
+    state.freeze_vesting(Clock::get()?.unix_timestamp)?;
+    state.arbiter_fee_earned = state.status == EscrowStatus::Disputed;
+    state.status = EscrowStatus::Resolved;
+    state.nonce = state
+        .nonce
+        .checked_add(1)
+        .ok_or(EscrowError::InvalidState)?;
+    state._reserved[0] = verdict;
+    state.split_buyer_bps = verdict_buyer_bps(verdict, 0)?;
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    profile.cases_handled = profile
+        .cases_handled
+        .checked_add(1)
+        .ok_or(EscrowError::Overflow)?;
+
+    emit!(DealResolved {
+        verdict,
+        buyer_bps: state.split_buyer_bps,
+    });
+
+    Ok(())
+}
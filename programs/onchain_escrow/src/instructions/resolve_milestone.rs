@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::MilestoneResolved;
+use crate::state::{
+    ArbiterProfile, ArbiterStatus, EscrowState, EscrowStatus, MilestoneSchedule, MilestoneStatus,
+};
+use crate::utils::verdict_buyer_bps;
+
+// Records the arbiter's verdict on one disputed tranche; `settle_milestone` pays it out.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct ResolveMilestone<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.arbiter == arbiter.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_resolve_milestone(
+    ctx: Context<ResolveMilestone>,
+    _deal_id: [u8; 16],
+    index: u8,
+    verdict: u8,
+    buyer_bps: u16,
+) -> Result<()> {
+    let buyer_bps = verdict_buyer_bps(verdict, buyer_bps)?;
+    let milestone = ctx
+        .accounts
+        .milestone_schedule
+        .get_mut(index)
+        .ok_or(EscrowError::InvalidMilestone)?;
+    require!(
+        milestone.status == MilestoneStatus::Disputed,
+        EscrowError::InvalidMilestone
+    );
+    milestone.record_verdict(verdict, buyer_bps);
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    profile.cases_handled = profile
+        .cases_handled
+        .checked_add(1)
+        .ok_or(EscrowError::Overflow)?;
+
+    emit!(MilestoneResolved {
+        escrow: ctx.accounts.escrow_state.key(),
+        index,
+        verdict,
+        buyer_bps,
+    });
+
+    Ok(())
+}
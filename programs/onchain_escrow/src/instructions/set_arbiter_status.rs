@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterStatusChanged;
+use crate::state::{ArbiterPool, ArbiterProfile, ArbiterStatus, Config};
+
+#[derive(Accounts)]
+pub struct SetArbiterStatus<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ EscrowError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"arbiter_pool"], bump = arbiter_pool.bump)]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter_profile.authority.as_ref()],
+        bump = arbiter_profile.bump,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_set_arbiter_status(ctx: Context<SetArbiterStatus>, active: bool) -> Result<()> {
+    let profile = &mut ctx.accounts.arbiter_profile;
+    if active {
+        require!(
+            profile.staked_amount >= ctx.accounts.config.min_stake,
+            EscrowError::InsufficientStake
+        );
+        require!(
+            ctx.accounts.arbiter_pool.add(profile.authority),
+            EscrowError::ArbiterPoolFull
+        );
+        profile.status = ArbiterStatus::Active;
+        profile.unstake_at = 0;
+    } else {
+        ctx.accounts.arbiter_pool.remove(&profile.authority);
+        profile.status = ArbiterStatus::Suspended;
+    }
+
+    emit!(ArbiterStatusChanged {
+        arbiter: profile.authority,
+        active,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PayoutAddressChanged;
+use crate::state::{EscrowState, EscrowStatus};
+
+#[derive(Accounts)]
+pub struct SetPayoutAddress<'info> {
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded | EscrowStatus::Disputed | EscrowStatus::Resolved) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+}
+
+pub fn handle_set_payout_address(ctx: Context<SetPayoutAddress>, payout_to: Pubkey) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let old_payout_to = state.payout_to;
+    state.payout_to = if payout_to == Pubkey::default() {
+        state.seller
+    } else {
+        payout_to
+    };
+
+    emit!(PayoutAddressChanged {
+        escrow: escrow_key,
+        old_payout_to,
+        new_payout_to: state.payout_to,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PayoutSplitSet;
+use crate::state::{EscrowState, EscrowStatus, PayoutShare, PayoutSplit, MAX_PAYOUT_RECIPIENTS};
+use crate::utils::BPS_DENOMINATOR;
+
+#[derive(Accounts)]
+pub struct SetPayoutSplit<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        init,
+        payer = seller,
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump,
+        space = PayoutSplit::space()
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_payout_split(
+    ctx: Context<SetPayoutSplit>,
+    shares: Vec<PayoutShare>,
+) -> Result<()> {
+    let count = shares.len();
+    require!(
+        count > 0 && count <= MAX_PAYOUT_RECIPIENTS,
+        EscrowError::InvalidPayoutSplit
+    );
+
+    let mut total_bps: u32 = 0;
+    for (i, share) in shares.iter().enumerate() {
+        require!(share.bps > 0, EscrowError::InvalidPayoutSplit);
+        require!(
+            !shares[..i].iter().any(|s| s.recipient == share.recipient),
+            EscrowError::InvalidPayoutSplit
+        );
+        total_bps += share.bps as u32;
+    }
+    require!(
+        total_bps <= BPS_DENOMINATOR as u32,
+        EscrowError::InvalidPayoutSplit
+    );
+
+    let state = &mut ctx.accounts.escrow_state;
+    let split = &mut ctx.accounts.payout_split;
+    split.escrow = state.key();
+    split.count = count as u8;
+    split.shares = [PayoutShare::default(); MAX_PAYOUT_RECIPIENTS];
+    split.shares[..count].copy_from_slice(&shares);
+    split.bump = ctx.bumps.payout_split;
+
+    state.has_payout_split = true;
+
+    emit!(PayoutSplitSet {
+        escrow: split.escrow,
+        shares,
+    });
+
+    Ok(())
+}
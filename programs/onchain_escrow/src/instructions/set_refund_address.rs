@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::RefundAddressChanged;
+use crate::state::{EscrowState, EscrowStatus, RefundPolicy};
+
+#[derive(Accounts)]
+pub struct SetRefundAddress<'info> {
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.refund_policy == RefundPolicy::Buyer @ EscrowError::Unauthorized,
+        constraint = matches!(escrow_state.status, EscrowStatus::Init | EscrowStatus::Funded | EscrowStatus::Disputed | EscrowStatus::Resolved) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+}
+
+pub fn handle_set_refund_address(ctx: Context<SetRefundAddress>, refund_to: Pubkey) -> Result<()> {
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let old_refund_to = state.refund_to;
+    state.refund_to = if refund_to == Pubkey::default() {
+        state.buyer
+    } else {
+        refund_to
+    };
+
+    emit!(RefundAddressChanged {
+        escrow: escrow_key,
+        old_refund_to,
+        new_refund_to: state.refund_to,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::{CollateralSettled, DealRefunded, DealReleased, DealSettled};
+use crate::state::{Config, EscrowState, EscrowStatus, PayoutSplit};
+use crate::utils::{
+    settle_payout, split_amount, vault_signer_seeds, verdict_buyer_bps, PayoutAccounts,
+    VERDICT_REFUND, VERDICT_RELEASE,
+};
+
+// Permissionless crank: pays out a resolved deal to the recorded parties' canonical ATAs,
+// creating them at the caller's expense when missing.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct Settle<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.status == EscrowStatus::Resolved @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: buyer refund owner recorded in escrow_state
+    #[account(address = escrow_state.refund_to @ EscrowError::Unauthorized)]
+    pub buyer_refund: UncheckedAccount<'info>,
+    /// CHECK: seller payout owner recorded in escrow_state
+    #[account(address = escrow_state.payout_to @ EscrowError::Unauthorized)]
+    pub seller_payout: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = buyer_refund
+    )]
+    pub buyer_ata: Account<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = seller_payout
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = arbiter_ata.owner == escrow_state.arbiter @ EscrowError::InvalidArbiter,
+        constraint = arbiter_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub arbiter_ata: Option<Account<'info, TokenAccount>>,
+    // Required when the deal has a payout split; recipient token accounts follow in
+    // `remaining_accounts`, in table order.
+    #[account(
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Option<Account<'info, PayoutSplit>>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Protocol fee recipients, required only when the respective share is non-zero.
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = referrer_ata.owner == escrow_state.referrer @ EscrowError::Unauthorized,
+        constraint = referrer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub referrer_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_settle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Settle<'info>>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let verdict = state._reserved[0];
+    let amount = state.amount;
+    require!(amount > 0, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.vault_ata.amount >= amount,
+        EscrowError::InsufficientFunds
+    );
+    let (buyer_amount, seller_amount) =
+        split_amount(amount, verdict_buyer_bps(verdict, state.split_buyer_bps)?)?;
+
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: Some(ctx.accounts.buyer_ata.to_account_info()),
+        seller_ata: Some(ctx.accounts.seller_ata.to_account_info()),
+        arbiter_ata: ctx.accounts.arbiter_ata.as_ref(),
+        treasury_ata: ctx.accounts.treasury_ata.as_ref(),
+        referrer_ata: ctx.accounts.referrer_ata.as_ref(),
+        payout_split: ctx.accounts.payout_split.as_ref(),
+        split_recipients: ctx.remaining_accounts,
+    };
+    let payout = settle_payout(
+        &accounts,
+        state,
+        escrow_state_key,
+        buyer_amount,
+        seller_amount,
+        true,
+        (0, 0),
+    )?;
+
+    let (buyer_collateral, seller_collateral) = payout.collateral;
+    if buyer_collateral + seller_collateral > 0 {
+        emit!(CollateralSettled {
+            escrow: escrow_state_key,
+            buyer_amount: buyer_collateral,
+            seller_amount: seller_collateral,
+        });
+    }
+
+    state.amount = 0;
+    match verdict {
+        VERDICT_RELEASE => {
+            state.status = EscrowStatus::Released;
+            emit!(DealReleased {
+                amount: payout.seller_amount,
+                arbiter_fee: payout.arbiter_fee,
+                late_penalty: payout.late_penalty,
+            });
+        }
+        VERDICT_REFUND => {
+            state.status = EscrowStatus::Refunded;
+            emit!(DealRefunded {
+                amount: payout.buyer_amount,
+                arbiter_fee: payout.arbiter_fee,
+            });
+        }
+        _ => {
+            state.status = EscrowStatus::Settled;
+            emit!(DealSettled {
+                buyer_amount: payout.buyer_amount,
+                seller_amount: payout.seller_amount,
+                arbiter_fee: payout.arbiter_fee,
+                late_penalty: payout.late_penalty,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::DisputeBondSettled;
+use crate::state::{BondForfeit, Config, EscrowState, EscrowStatus};
+use crate::utils::{vault_signer_seeds, vault_transfer};
+
+// Permissionless once the verdict is paid out: returns the bond to a prevailing poster,
+// otherwise forfeits it to the counterparty's payout/refund address or the treasury. Waiting for the payout lets
+// a `LoserPays` arbiter fee come out of a forfeited bond first.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct SettleDisputeBond<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = matches!(escrow_state.status, EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Settled) @ EscrowError::InvalidState,
+        constraint = escrow_state.bond_posted > 0 @ EscrowError::InsufficientFunds,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = recipient_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub recipient_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_settle_dispute_bond(
+    ctx: Context<SettleDisputeBond>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let bond = state.bond_posted;
+    let forfeited = !state.bond_poster_prevailed();
+    let recipient = if !forfeited {
+        state.bond_poster
+    } else if state.bond_forfeit_to == BondForfeit::Treasury {
+        ctx.accounts.config.treasury
+    } else if state.bond_poster == state.buyer {
+        state.payout_to
+    } else {
+        state.refund_to
+    };
+    require_keys_eq!(
+        ctx.accounts.recipient_ata.owner,
+        recipient,
+        EscrowError::Unauthorized
+    );
+
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.recipient_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        bond,
+    )?;
+
+    state.bond_posted = 0;
+
+    emit!(DisputeBondSettled {
+        escrow: escrow_state_key,
+        recipient,
+        amount: bond,
+        forfeited,
+    });
+
+    Ok(())
+}
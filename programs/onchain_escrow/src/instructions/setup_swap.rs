@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::SwapConfigured;
+use crate::state::{EscrowState, EscrowStatus};
+
+// Turns a deal into a two-sided swap: the buyer funds mint A through `fund` as usual and
+// the seller deposits `swap_amount` of mint B into a second vault owned by the same PDA.
+// Both parties sign, since the swap replaces what the buyer receives.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct SetupSwap<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub buyer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        // A swap settles all at once, so it cannot carry a release schedule.
+        constraint = escrow_state.vest_end == 0 && escrow_state.periods == 0 @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vaults
+    #[account(
+        seeds = [b"vault", escrow_state.key().as_ref()],
+        bump = escrow_state.bump
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(constraint = swap_mint.key() != escrow_state.mint @ EscrowError::MintMismatch)]
+    pub swap_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = seller,
+        associated_token::mint = swap_mint,
+        associated_token::authority = vault_authority
+    )]
+    pub swap_vault_ata: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_setup_swap(
+    ctx: Context<SetupSwap>,
+    _deal_id: [u8; 16],
+    swap_amount: u64,
+    swap_by: i64,
+) -> Result<()> {
+    require!(swap_amount > 0, EscrowError::InsufficientFunds);
+    let now = Clock::get()?.unix_timestamp;
+    require!(swap_by > now, EscrowError::DeadlinePassed);
+
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    state.swap_mint = ctx.accounts.swap_mint.key();
+    state.swap_vault_ata = ctx.accounts.swap_vault_ata.key();
+    state.swap_amount = swap_amount;
+    state.swap_by = swap_by;
+    state.swap_deposited = false;
+
+    emit!(SwapConfigured {
+        escrow: escrow_key,
+        swap_mint: state.swap_mint,
+        swap_amount,
+        swap_by,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterSlashed;
+use crate::state::{ArbiterPool, ArbiterProfile, ArbiterStatus, Config};
+use crate::utils::vault_transfer;
+
+// Appeals are decided off-chain; the admin records an overturned case here.
+#[derive(Accounts)]
+pub struct SlashArbiter<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ EscrowError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"arbiter_pool"], bump = arbiter_pool.bump)]
+    pub arbiter_pool: Account<'info, ArbiterPool>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter_profile.authority.as_ref()],
+        bump = arbiter_profile.bump,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+    #[account(
+        mut,
+        address = arbiter_profile.stake_vault,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == config.stake_mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_slash_arbiter(
+    ctx: Context<SlashArbiter>,
+    amount: u64,
+    overturned: bool,
+) -> Result<()> {
+    let authority = ctx.accounts.arbiter_profile.authority;
+    let bump_seed = [ctx.accounts.arbiter_profile.bump];
+    let seeds: [&[u8]; 3] = [b"arbiter".as_ref(), authority.as_ref(), bump_seed.as_ref()];
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    require!(
+        amount <= profile.staked_amount,
+        EscrowError::InsufficientStake
+    );
+
+    if amount > 0 {
+        vault_transfer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.stake_vault.to_account_info(),
+            ctx.accounts.treasury_ata.to_account_info(),
+            profile.to_account_info(),
+            &signer_seeds,
+            amount,
+        )?;
+    }
+
+    profile.staked_amount -= amount;
+    profile.slashed_amount = profile
+        .slashed_amount
+        .checked_add(amount)
+        .ok_or(EscrowError::Overflow)?;
+    if overturned {
+        profile.cases_overturned = profile
+            .cases_overturned
+            .checked_add(1)
+            .ok_or(EscrowError::Overflow)?;
+    }
+    if profile.staked_amount < ctx.accounts.config.min_stake {
+        ctx.accounts.arbiter_pool.remove(&authority);
+        profile.status = ArbiterStatus::Suspended;
+    }
+
+    emit!(ArbiterSlashed {
+        arbiter: authority,
+        amount,
+        overturned,
+        remaining_stake: profile.staked_amount,
+    });
+
+    Ok(())
+}
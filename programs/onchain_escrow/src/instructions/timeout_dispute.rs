@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::{ArbiterTimedOut, DealResolved};
+use crate::state::{Config, EscrowState, EscrowStatus, TimeoutFallback};
+use crate::utils::{verdict_buyer_bps, BPS_DENOMINATOR, VERDICT_REFUND, VERDICT_SPLIT};
+
+// Permissionless: anyone may apply the configured fallback once the arbiter deadline passes.
+#[derive(Accounts)]
+pub struct TimeoutDispute<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.status == EscrowStatus::Disputed @ EscrowError::InvalidState,
+        constraint = escrow_state.resolve_by > 0 @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handle_timeout_dispute(ctx: Context<TimeoutDispute>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    require!(now > state.resolve_by, EscrowError::DeadlineNotReached);
+
+    let timed_out_arbiter = state.arbiter;
+    let resolve_by = state.resolve_by;
+    let fallback = config.fallback_for(state, &timed_out_arbiter);
+
+    state.nonce = state
+        .nonce
+        .checked_add(1)
+        .ok_or(EscrowError::InvalidState)?;
+
+    let mut new_arbiter = Pubkey::default();
+    match fallback {
+        TimeoutFallback::RefundBuyer | TimeoutFallback::SplitEvenly => {
+            let verdict = if fallback == TimeoutFallback::RefundBuyer {
+                VERDICT_REFUND
+            } else {
+                VERDICT_SPLIT
+            };
+            state.status = EscrowStatus::Resolved;
+            state._reserved[0] = verdict;
+            state.split_buyer_bps = verdict_buyer_bps(verdict, BPS_DENOMINATOR / 2)?;
+            state.resolve_by = 0;
+
+            emit!(DealResolved {
+                verdict,
+                buyer_bps: state.split_buyer_bps,
+            });
+        }
+        TimeoutFallback::Escalate => {
+            new_arbiter = config.backup_arbiter;
+            state.arbiter = new_arbiter;
+            state.resolve_by = config.resolve_by(now)?;
+        }
+    }
+
+    emit!(ArbiterTimedOut {
+        escrow: escrow_key,
+        arbiter: timed_out_arbiter,
+        resolve_by,
+        fallback,
+        new_arbiter,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::{ArbiterTimedOut, MilestoneResolved};
+use crate::state::{
+    Config, EscrowState, EscrowStatus, MilestoneSchedule, MilestoneStatus, PanelVote,
+    TimeoutFallback, MAX_PANEL_ARBITERS,
+};
+use crate::utils::{verdict_buyer_bps, BPS_DENOMINATOR, VERDICT_REFUND, VERDICT_SPLIT};
+
+// Permissionless: applies the configured fallback to a milestone dispute the arbiter
+// left unresolved past its deadline. Escalation hands the whole deal to the backup
+// arbiter, as `timeout_dispute` does.
+#[derive(Accounts)]
+pub struct TimeoutMilestone<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handle_timeout_milestone(ctx: Context<TimeoutMilestone>, index: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &ctx.accounts.config;
+    let escrow_key = ctx.accounts.escrow_state.key();
+    let state = &mut ctx.accounts.escrow_state;
+    let milestone = ctx
+        .accounts
+        .milestone_schedule
+        .get_mut(index)
+        .ok_or(EscrowError::InvalidMilestone)?;
+    require!(
+        milestone.status == MilestoneStatus::Disputed && milestone.resolve_by > 0,
+        EscrowError::InvalidMilestone
+    );
+    require!(now > milestone.resolve_by, EscrowError::DeadlineNotReached);
+
+    let timed_out_arbiter = state.arbiter;
+    let resolve_by = milestone.resolve_by;
+    let fallback = config.fallback_for(state, &timed_out_arbiter);
+
+    let mut new_arbiter = Pubkey::default();
+    match fallback {
+        TimeoutFallback::RefundBuyer | TimeoutFallback::SplitEvenly => {
+            let verdict = if fallback == TimeoutFallback::RefundBuyer {
+                VERDICT_REFUND
+            } else {
+                VERDICT_SPLIT
+            };
+            let buyer_bps = verdict_buyer_bps(verdict, BPS_DENOMINATOR / 2)?;
+            milestone.record_verdict(verdict, buyer_bps);
+
+            emit!(MilestoneResolved {
+                escrow: escrow_key,
+                index,
+                verdict,
+                buyer_bps,
+            });
+        }
+        TimeoutFallback::Escalate => {
+            new_arbiter = config.backup_arbiter;
+            state.arbiter = new_arbiter;
+            state.nonce = state
+                .nonce
+                .checked_add(1)
+                .ok_or(EscrowError::InvalidState)?;
+            milestone.resolve_by = config.resolve_by(now)?;
+            milestone.votes = [PanelVote::default(); MAX_PANEL_ARBITERS];
+        }
+    }
+
+    emit!(ArbiterTimedOut {
+        escrow: escrow_key,
+        arbiter: timed_out_arbiter,
+        resolve_by,
+        fallback,
+        new_arbiter,
+    });
+
+    Ok(())
+}
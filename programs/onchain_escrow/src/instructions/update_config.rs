@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ConfigUpdated;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ EscrowError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handle_update_config(
+    ctx: Context<UpdateConfig>,
+    new_admin: Pubkey,
+    treasury: Pubkey,
+    min_stake: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = new_admin;
+    config.treasury = treasury;
+    config.min_stake = min_stake;
+
+    emit!(ConfigUpdated {
+        admin: new_admin,
+        treasury,
+        min_stake,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::DisputePolicyUpdated;
+use crate::instructions::UpdateConfig;
+use crate::state::TimeoutFallback;
+use crate::utils::assert_active_arbiter;
+
+// A non-default backup arbiter must be registered and active; its ArbiterProfile is
+// passed as the first remaining account.
+pub fn handle_update_dispute_policy(
+    ctx: Context<UpdateConfig>,
+    resolution_window: i64,
+    timeout_fallback: TimeoutFallback,
+    backup_arbiter: Pubkey,
+) -> Result<()> {
+    require!(resolution_window >= 0, EscrowError::InvalidState);
+    if timeout_fallback == TimeoutFallback::Escalate {
+        require!(
+            backup_arbiter != Pubkey::default(),
+            EscrowError::InvalidArbiter
+        );
+        // Without a window the escalated dispute would get no deadline and could never
+        // time out again.
+        require!(resolution_window > 0, EscrowError::InvalidState);
+    }
+    if backup_arbiter != Pubkey::default() {
+        let profile = ctx
+            .remaining_accounts
+            .first()
+            .ok_or(EscrowError::InvalidArbiter)?;
+        assert_active_arbiter(profile, &backup_arbiter)?;
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.resolution_window = resolution_window;
+    config.timeout_fallback = timeout_fallback;
+    config.backup_arbiter = backup_arbiter;
+
+    emit!(DisputePolicyUpdated {
+        resolution_window,
+        timeout_fallback,
+        backup_arbiter,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::FeeConfigUpdated;
+use crate::instructions::UpdateConfig;
+use crate::utils::BPS_DENOMINATOR;
+
+pub fn handle_update_fee_config(
+    ctx: Context<UpdateConfig>,
+    max_arbiter_fee_bps: u16,
+    max_referral_bps: u16,
+) -> Result<()> {
+    require!(
+        max_arbiter_fee_bps <= BPS_DENOMINATOR && max_referral_bps <= BPS_DENOMINATOR,
+        EscrowError::FeeTooHigh
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.max_arbiter_fee_bps = max_arbiter_fee_bps;
+    config.max_referral_bps = max_referral_bps;
+
+    emit!(FeeConfigUpdated {
+        max_arbiter_fee_bps,
+        max_referral_bps,
+    });
+
+    Ok(())
+}
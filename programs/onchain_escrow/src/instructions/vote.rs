@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::{DealResolved, PanelVoteCast};
+use crate::state::{
+    ArbiterPanel, ArbiterProfile, ArbiterStatus, EscrowState, EscrowStatus, PanelVote,
+};
+use crate::utils::verdict_buyer_bps;
+
+#[derive(Accounts)]
+pub struct Vote<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow_state.arbiter == arbiter_panel.key() @ EscrowError::InvalidPanel,
+        constraint = matches!(escrow_state.status, EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_swap() @ EscrowError::InvalidState,
+        constraint = !escrow_state.is_milestone @ EscrowError::InvalidMilestone,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        mut,
+        seeds = [b"panel", escrow_state.key().as_ref()],
+        bump = arbiter_panel.bump,
+    )]
+    pub arbiter_panel: Account<'info, ArbiterPanel>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_vote(ctx: Context<Vote>, verdict: u8, buyer_bps: u16) -> Result<()> {
+    let vote = PanelVote {
+        verdict,
+        buyer_bps: verdict_buyer_bps(verdict, buyer_bps)?,
+    };
+
+    let panel = &mut ctx.accounts.arbiter_panel;
+    let index = panel
+        .arbiter_index(&ctx.accounts.arbiter.key())
+        .ok_or(EscrowError::Unauthorized)?;
+    // Until the threshold is met a member may change their vote, so a split panel can
+    // converge; a dispute that stays deadlocked falls to `timeout_dispute`.
+    let previous = panel.votes[index];
+    require!(previous != vote, EscrowError::AlreadyVoted);
+    let first_vote = previous.verdict == 0;
+
+    panel.votes[index] = vote;
+    if first_vote {
+        panel.votes_cast = panel
+            .votes_cast
+            .checked_add(1)
+            .ok_or(EscrowError::Overflow)?;
+
+        let profile = &mut ctx.accounts.arbiter_profile;
+        profile.cases_handled = profile
+            .cases_handled
+            .checked_add(1)
+            .ok_or(EscrowError::Overflow)?;
+    }
+    let matching_votes = panel.tally(&vote);
+
+    emit!(PanelVoteCast {
+        escrow: panel.escrow,
+        arbiter: ctx.accounts.arbiter.key(),
+        verdict,
+        buyer_bps: vote.buyer_bps,
+        matching_votes,
+        votes_cast: panel.votes_cast,
+    });
+
+    if matching_votes >= panel.threshold {
+        let state = &mut ctx.accounts.escrow_state;
+        state.freeze_vesting(Clock::get()?.unix_timestamp)?;
+        state.arbiter_fee_earned = state.status == EscrowStatus::Disputed;
+        state.status = EscrowStatus::Resolved;
+        state.nonce = state
+            .nonce
+            .checked_add(1)
+            .ok_or(EscrowError::InvalidState)?;
+        state._reserved[0] = verdict;
+        state.split_buyer_bps = vote.buyer_bps;
+
+        emit!(DealResolved {
+            verdict,
+            buyer_bps: vote.buyer_bps,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::{MilestoneResolved, MilestoneVoteCast};
+use crate::state::{
+    ArbiterPanel, ArbiterProfile, ArbiterStatus, EscrowState, EscrowStatus, MilestoneSchedule,
+    MilestoneStatus, PanelVote,
+};
+use crate::utils::verdict_buyer_bps;
+
+// Panel counterpart of `resolve_milestone`: the verdict is recorded once `threshold`
+// members agree on it.
+#[derive(Accounts)]
+pub struct VoteMilestone<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        constraint = escrow_state.arbiter == arbiter_panel.key() @ EscrowError::InvalidPanel,
+        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    #[account(
+        seeds = [b"panel", escrow_state.key().as_ref()],
+        bump = arbiter_panel.bump,
+    )]
+    pub arbiter_panel: Account<'info, ArbiterPanel>,
+    #[account(
+        mut,
+        seeds = [b"milestones", escrow_state.key().as_ref()],
+        bump = milestone_schedule.bump,
+    )]
+    pub milestone_schedule: Account<'info, MilestoneSchedule>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status == ArbiterStatus::Active @ EscrowError::InvalidArbiter,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+}
+
+pub fn handle_vote_milestone(
+    ctx: Context<VoteMilestone>,
+    index: u8,
+    verdict: u8,
+    buyer_bps: u16,
+) -> Result<()> {
+    let vote = PanelVote {
+        verdict,
+        buyer_bps: verdict_buyer_bps(verdict, buyer_bps)?,
+    };
+
+    let panel = &ctx.accounts.arbiter_panel;
+    let member = panel
+        .arbiter_index(&ctx.accounts.arbiter.key())
+        .ok_or(EscrowError::Unauthorized)?;
+    let milestone = ctx
+        .accounts
+        .milestone_schedule
+        .get_mut(index)
+        .ok_or(EscrowError::InvalidMilestone)?;
+    require!(
+        milestone.status == MilestoneStatus::Disputed,
+        EscrowError::InvalidMilestone
+    );
+
+    // Members may change their vote until the threshold is met, as in `vote`.
+    let previous = milestone.votes[member];
+    require!(previous != vote, EscrowError::AlreadyVoted);
+    milestone.votes[member] = vote;
+    if previous.verdict == 0 {
+        let profile = &mut ctx.accounts.arbiter_profile;
+        profile.cases_handled = profile
+            .cases_handled
+            .checked_add(1)
+            .ok_or(EscrowError::Overflow)?;
+    }
+    let matching_votes = panel.tally_in(&milestone.votes, &vote);
+
+    let escrow = ctx.accounts.escrow_state.key();
+    emit!(MilestoneVoteCast {
+        escrow,
+        index,
+        arbiter: ctx.accounts.arbiter.key(),
+        verdict,
+        buyer_bps: vote.buyer_bps,
+        matching_votes,
+    });
+
+    if matching_votes >= panel.threshold {
+        milestone.record_verdict(verdict, vote.buyer_bps);
+
+        emit!(MilestoneResolved {
+            escrow,
+            index,
+            verdict,
+            buyer_bps: vote.buyer_bps,
+        });
+    }
+
+    Ok(())
+}
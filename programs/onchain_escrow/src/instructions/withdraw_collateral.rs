@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::CollateralSettled;
+use crate::state::{EscrowState, EscrowStatus};
+use crate::utils::{vault_signer_seeds, vault_transfer};
+
+// Returns collateral not consumed by a verdict: before the deal is funded, or after it
+// ended without a deal-level verdict (vesting, swaps, cancellation) or through milestones,
+// whose refund and split verdicts already took their share in `settle_milestone`.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct WithdrawCollateral<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.collateral_posted > 0 @ EscrowError::InsufficientFunds,
+        constraint = !matches!(
+            escrow_state.status,
+            EscrowStatus::Funded | EscrowStatus::PartiallyFunded | EscrowStatus::Disputed | EscrowStatus::Resolved
+        ) @ EscrowError::InvalidState,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = seller_ata.owner == seller.key() @ EscrowError::Unauthorized,
+        constraint = seller_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_withdraw_collateral(
+    ctx: Context<WithdrawCollateral>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let amount = state.collateral_posted;
+    vault_transfer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_ata.to_account_info(),
+        ctx.accounts.seller_ata.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        &signer_seeds,
+        amount,
+    )?;
+    state.collateral_posted = 0;
+
+    emit!(CollateralSettled {
+        escrow: escrow_state_key,
+        buyer_amount: 0,
+        seller_amount: amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterStakeWithdrawn;
+use crate::state::{ArbiterProfile, ArbiterStatus};
+use crate::utils::vault_transfer;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"arbiter", arbiter.key().as_ref()],
+        bump = arbiter_profile.bump,
+        constraint = arbiter_profile.status != ArbiterStatus::Active @ EscrowError::InvalidState,
+        constraint = arbiter_profile.unstake_at > 0 @ EscrowError::InvalidState,
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+    #[account(
+        mut,
+        address = arbiter_profile.stake_vault,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = arbiter_ata.owner == arbiter.key() @ EscrowError::Unauthorized,
+        constraint = arbiter_ata.mint == stake_vault.mint @ EscrowError::MintMismatch,
+    )]
+    pub arbiter_ata: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.arbiter_profile.unstake_at,
+        EscrowError::DeadlineNotReached
+    );
+
+    let authority = ctx.accounts.arbiter_profile.authority;
+    let bump_seed = [ctx.accounts.arbiter_profile.bump];
+    let seeds: [&[u8]; 3] = [b"arbiter".as_ref(), authority.as_ref(), bump_seed.as_ref()];
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let profile = &mut ctx.accounts.arbiter_profile;
+    let amount = profile.staked_amount;
+    if amount > 0 {
+        vault_transfer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.stake_vault.to_account_info(),
+            ctx.accounts.arbiter_ata.to_account_info(),
+            profile.to_account_info(),
+            &signer_seeds,
+            amount,
+        )?;
+    }
+    profile.staked_amount = 0;
+
+    emit!(ArbiterStakeWithdrawn {
+        arbiter: authority,
+        amount,
+    });
+
+    Ok(())
+}
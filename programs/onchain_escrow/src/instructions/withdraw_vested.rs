@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::EscrowError;
+use crate::events::VestedWithdrawn;
+use crate::state::{Config, EscrowState, EscrowStatus, PayoutSplit};
+use crate::utils::{settle_payout, vault_signer_seeds, PayoutAccounts};
+
+// Vesting runs while the deal is funded; after a dispute freezes it, whatever had vested
+// stays withdrawable regardless of how the dispute ends.
+#[derive(Accounts)]
+#[instruction(deal_id: [u8; 16])]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow",
+            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
+        ],
+        bump,
+        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
+        constraint = escrow_state.vest_end > 0 @ EscrowError::InvalidVesting,
+    )]
+    pub escrow_state: Account<'info, EscrowState>,
+    /// CHECK: PDA authority for the vault
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = escrow_state.vault_ata,
+        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+    #[account(address = escrow_state.mint @ EscrowError::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    /// CHECK: seller payout owner recorded in escrow_state
+    #[account(address = escrow_state.payout_to @ EscrowError::Unauthorized)]
+    pub seller_payout: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = seller_payout
+    )]
+    pub seller_ata: Account<'info, TokenAccount>,
+    // Required when the deal has a payout split; recipient token accounts follow in
+    // `remaining_accounts`, in table order.
+    #[account(
+        seeds = [b"payout_split", escrow_state.key().as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Option<Account<'info, PayoutSplit>>,
+    // Always initialized: `initiate` and `migrate_escrow` require it.
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    // Protocol fee recipients, required only when the respective share is non-zero.
+    #[account(
+        mut,
+        constraint = treasury_ata.owner == config.treasury @ EscrowError::Unauthorized,
+        constraint = treasury_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = referrer_ata.owner == escrow_state.referrer @ EscrowError::Unauthorized,
+        constraint = referrer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
+    )]
+    pub referrer_ata: Option<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_withdraw_vested<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawVested<'info>>,
+    _deal_id: [u8; 16],
+) -> Result<()> {
+    let escrow_state_key = ctx.accounts.escrow_state.key();
+    let bump_seed = [ctx.accounts.escrow_state.bump];
+    let seeds = vault_signer_seeds(
+        &escrow_state_key,
+        &bump_seed,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
+
+    let state = &mut ctx.accounts.escrow_state;
+    let frozen = state.vest_frozen_at > 0;
+    if !frozen {
+        require!(
+            state.status == EscrowStatus::Funded,
+            EscrowError::InvalidState
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let available = state.vested_unwithdrawn(now)?;
+    require!(available > 0, EscrowError::InsufficientFunds);
+    require!(
+        ctx.accounts.vault_ata.amount >= available,
+        EscrowError::InsufficientFunds
+    );
+
+    let accounts = PayoutAccounts {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        vault_ata: ctx.accounts.vault_ata.to_account_info(),
+        vault_authority: ctx.accounts.vault_authority.to_account_info(),
+        signer_seeds: &signer_seeds,
+        buyer_ata: None,
+        seller_ata: Some(ctx.accounts.seller_ata.to_account_info()),
+        arbiter_ata: None,
+        treasury_ata: ctx.accounts.treasury_ata.as_ref(),
+        referrer_ata: ctx.accounts.referrer_ata.as_ref(),
+        payout_split: ctx.accounts.payout_split.as_ref(),
+        split_recipients: ctx.remaining_accounts,
+    };
+    settle_payout(
+        &accounts,
+        state,
+        escrow_state_key,
+        0,
+        available,
+        false,
+        (0, 0),
+    )?;
+
+    if frozen {
+        state.vest_claimable -= available;
+    } else {
+        state.amount = state
+            .amount
+            .checked_sub(available)
+            .ok_or(EscrowError::Overflow)?;
+        if state.amount == 0 {
+            state.status = EscrowStatus::Released;
+        }
+    }
+    state.vest_withdrawn = state
+        .vest_withdrawn
+        .checked_add(available)
+        .ok_or(EscrowError::Overflow)?;
+
+    emit!(VestedWithdrawn {
+        escrow: escrow_state_key,
+        amount: available,
+        total_withdrawn: state.vest_withdrawn,
+        remaining: state.amount,
+    });
+
+    Ok(())
+}
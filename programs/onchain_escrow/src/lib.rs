@@ -1,32 +1,57 @@
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
-use anchor_spl::token;
 
 declare_id!("B1a1oejNg8uWz7USuuFSqmRQRUSZ95kk2e4PzRZ7Uti4");
 
 pub mod errors;
 pub mod events;
+pub mod instructions;
 pub mod state;
 pub mod utils;
 
-use crate::errors::EscrowError;
-use crate::events::*;
-use crate::state::*;
-use crate::utils::*;
+pub use errors::EscrowError;
+pub use instructions::*;
+use crate::state::{
+    ArbiterFeeTerms, DisputeBondTerms, LatePenaltyTerms, MilestoneTerms, PayoutShare, RefundPolicy,
+    SubscriptionTerms, TimeoutFallback, VestingTerms,
+};
 
 #[program]
 pub mod onchain_escrow_program {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initiate(
         ctx: Context<Initiate>,
         amount: u64,
         fee_bps: u16,
         dispute_by: i64,
         deal_id: [u8; 16], // UUID as 16 bytes
+        arbiter_fee: ArbiterFeeTerms,
+        dispute_bond: DisputeBondTerms,
+        fund_by: i64, // Funding deadline, whole or in installments (0 = none)
+        vesting: VestingTerms,
+        subscription: SubscriptionTerms,
+        collateral: u64, // Seller performance bond (0 = none)
+        late_penalty: LatePenaltyTerms,
+        cancel_fee_bps: u16, // Kept by the seller if the buyer cancels after funding
+        referral_bps: u16, // Referrer's share of the protocol fee, capped by config
     ) -> Result<()> {
-        handle_initiate(ctx, amount, fee_bps, dispute_by, deal_id)
+        handle_initiate(
+            ctx,
+            amount,
+            fee_bps,
+            dispute_by,
+            deal_id,
+            arbiter_fee,
+            dispute_bond,
+            fund_by,
+            vesting,
+            subscription,
+            collateral,
+            late_penalty,
+            cancel_fee_bps,
+            referral_bps,
+        )
     }
 
     pub fn fund(
@@ -36,16 +61,43 @@ pub mod onchain_escrow_program {
         handle_fund(ctx, deal_id)
     }
 
+    pub fn fund_on_behalf(
+        ctx: Context<FundOnBehalf>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        refund_policy: RefundPolicy,
+    ) -> Result<()> {
+        handle_fund_on_behalf(ctx, deal_id, refund_policy)
+    }
+
+    pub fn fund_partial(
+        ctx: Context<FundPartial>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        deposit: u64,
+    ) -> Result<()> {
+        handle_fund_partial(ctx, deal_id, deposit)
+    }
+
+    pub fn cancel_funding(
+        ctx: Context<CancelFunding>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_cancel_funding(ctx, deal_id)
+    }
+
     pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
         handle_open_dispute(ctx)
     }
 
+    pub fn assign_arbiter(ctx: Context<AssignArbiter>) -> Result<()> {
+        handle_assign_arbiter(ctx)
+    }
+
     pub fn resolve(ctx: Context<Resolve>, verdict: u8) -> Result<()> {
         handle_resolve(ctx, verdict)
     }
 
-    pub fn release(
-        ctx: Context<Release>,
+    pub fn release<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Release<'info>>,
         deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
     ) -> Result<()> {
         handle_release(ctx, deal_id)
@@ -57,391 +109,276 @@ pub mod onchain_escrow_program {
     ) -> Result<()> {
         handle_refund(ctx, deal_id)
     }
-}
 
-// --- Initiate Handler ---
-#[derive(Accounts)]
-#[instruction(deal_id: [u8; 16])]
-pub struct Initiate<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    /// CHECK: seller address
-    pub seller: UncheckedAccount<'info>,
-    /// CHECK: buyer address
-    pub buyer: UncheckedAccount<'info>,
-    /// CHECK: arbiter authority configured off-chain
-    pub arbiter: UncheckedAccount<'info>,
-    pub mint: Account<'info, Mint>,
-    #[account(
-        init,
-        payer = payer,
-        seeds = [
-            b"escrow",
-            deal_id.as_ref(), // deal_id as [u8; 16] converted to slice
-        ],
-        bump,
-        space = EscrowState::space()
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-    /// CHECK: PDA derived for vault authority
-    #[account(
-        seeds = [b"vault", escrow_state.key().as_ref()],
-        bump
-    )]
-    pub vault_authority: UncheckedAccount<'info>,
-    #[account(
-        init,
-        payer = payer,
-        associated_token::mint = mint,
-        associated_token::authority = vault_authority
-    )]
-    pub vault_ata: Account<'info, TokenAccount>,
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    pub fn create_panel(
+        ctx: Context<CreatePanel>,
+        threshold: u8,
+        arbiters: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_create_panel(ctx, threshold, arbiters)
+    }
 
-pub fn handle_initiate(
-    ctx: Context<Initiate>,
-    amount: u64,
-    fee_bps: u16,
-    dispute_by: i64,
-    _deal_id: [u8; 16], // Still in instruction signature but not used in PDA seeds
-) -> Result<()> {
-    require!(amount > 0, EscrowError::InsufficientFunds);
-
-    let now = Clock::get()?.unix_timestamp;
-    let vault_bump = ctx.bumps.vault_authority;
-
-    let state = &mut ctx.accounts.escrow_state;
-    state.version = EscrowState::VERSION;
-    state.bump = vault_bump;
-    state.seller = ctx.accounts.seller.key();
-    state.buyer = ctx.accounts.buyer.key();
-    state.mint = ctx.accounts.mint.key();
-    state.amount = amount;
-    state.fee_bps = fee_bps;
-    state.vault_ata = ctx.accounts.vault_ata.key();
-    state.arbiter = ctx.accounts.arbiter.key();
-    state.status = EscrowStatus::Init;
-    state.nonce = 0;
-    state.created_at = now;
-    state.dispute_by = dispute_by;
-    state._reserved = [0; 32];
-
-    emit!(DealInitiated {
-        seller: state.seller,
-        buyer: state.buyer,
-        mint: state.mint,
-        amount,
-    });
-
-    Ok(())
-}
+    pub fn vote(ctx: Context<Vote>, verdict: u8, buyer_bps: u16) -> Result<()> {
+        handle_vote(ctx, verdict, buyer_bps)
+    }
 
-// --- Fund Handler ---
-#[derive(Accounts)]
-#[instruction(deal_id: [u8; 16])]
-pub struct Fund<'info> {
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [
-            b"escrow",
-            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
-        ],
-        bump,
-        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
-        constraint = escrow_state.status == EscrowStatus::Init @ EscrowError::InvalidState,
-        constraint = escrow_state.amount > 0 @ EscrowError::InsufficientFunds,
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-    #[account(
-        mut,
-        constraint = buyer_ata.owner == buyer.key() @ EscrowError::Unauthorized,
-        constraint = buyer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-    )]
-    pub buyer_ata: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        address = escrow_state.vault_ata,
-        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-    )]
-    pub vault_ata: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury: Pubkey,
+        min_stake: u64,
+    ) -> Result<()> {
+        handle_initialize_config(ctx, treasury, min_stake)
+    }
 
-pub fn handle_fund(ctx: Context<Fund>, _deal_id: [u8; 16]) -> Result<()> {
-    let expected_vault = ctx.accounts.vault_authority_key()?;
-    let state = &mut ctx.accounts.escrow_state;
-    require_keys_eq!(
-        ctx.accounts.vault_ata.owner,
-        expected_vault,
-        EscrowError::VaultOwnerMismatch
-    );
-
-    let amount = state.amount;
-    require!(amount > 0, EscrowError::InsufficientFunds);
-    require!(
-        ctx.accounts.buyer_ata.amount >= amount,
-        EscrowError::InsufficientFunds
-    );
-
-    let transfer_accounts = Transfer {
-        from: ctx.accounts.buyer_ata.to_account_info(),
-        to: ctx.accounts.vault_ata.to_account_info(),
-        authority: ctx.accounts.buyer.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_accounts);
-    token::transfer(cpi_ctx, amount)?;
-
-    state.status = EscrowStatus::Funded;
-
-    emit!(DealFunded {
-        buyer: state.buyer,
-        amount,
-    });
-
-    Ok(())
-}
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Pubkey,
+        treasury: Pubkey,
+        min_stake: u64,
+    ) -> Result<()> {
+        handle_update_config(ctx, new_admin, treasury, min_stake)
+    }
 
-impl<'info> Fund<'info> {
-    fn vault_authority_key(&self) -> Result<Pubkey> {
-        Pubkey::create_program_address(
-            &[b"vault", self.escrow_state.key().as_ref(), &[self.escrow_state.bump]],
-            &crate::ID,
-        )
-        .map_err(|_| error!(EscrowError::VaultOwnerMismatch))
+    pub fn register_arbiter(ctx: Context<RegisterArbiter>, stake: u64) -> Result<()> {
+        handle_register_arbiter(ctx, stake)
     }
-}
 
-// --- Open Dispute Handler ---
-#[derive(Accounts)]
-pub struct OpenDispute<'info> {
-    pub caller: Signer<'info>,
-    #[account(
-        mut,
-        constraint = escrow_state.status == EscrowStatus::Funded @ EscrowError::InvalidState,
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-}
+    pub fn set_arbiter_status(ctx: Context<SetArbiterStatus>, active: bool) -> Result<()> {
+        handle_set_arbiter_status(ctx, active)
+    }
+
+    pub fn deactivate_arbiter(ctx: Context<DeactivateArbiter>) -> Result<()> {
+        handle_deactivate_arbiter(ctx)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        handle_withdraw_stake(ctx)
+    }
 
-pub fn handle_open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
-    let state = &mut ctx.accounts.escrow_state;
+    pub fn slash_arbiter(
+        ctx: Context<SlashArbiter>,
+        amount: u64,
+        overturned: bool,
+    ) -> Result<()> {
+        handle_slash_arbiter(ctx, amount, overturned)
+    }
 
-    require!(
-        ctx.accounts.caller.key() == state.seller || ctx.accounts.caller.key() == state.buyer,
-        EscrowError::Unauthorized
-    );
+    pub fn update_dispute_policy(
+        ctx: Context<UpdateConfig>,
+        resolution_window: i64,
+        timeout_fallback: TimeoutFallback,
+        backup_arbiter: Pubkey,
+    ) -> Result<()> {
+        handle_update_dispute_policy(ctx, resolution_window, timeout_fallback, backup_arbiter)
+    }
 
-    if state.dispute_by > 0 {
-        let now = Clock::get()?.unix_timestamp;
-        require!(now <= state.dispute_by, EscrowError::DeadlinePassed);
+    pub fn timeout_dispute(ctx: Context<TimeoutDispute>) -> Result<()> {
+        handle_timeout_dispute(ctx)
     }
 
-    state.status = EscrowStatus::Disputed;
+    pub fn update_fee_config(
+        ctx: Context<UpdateConfig>,
+        max_arbiter_fee_bps: u16,
+        max_referral_bps: u16,
+    ) -> Result<()> {
+        handle_update_fee_config(ctx, max_arbiter_fee_bps, max_referral_bps)
+    }
 
-    emit!(DealDisputed { by: ctx.accounts.caller.key() });
+    pub fn settle_dispute_bond(
+        ctx: Context<SettleDisputeBond>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_settle_dispute_bond(ctx, deal_id)
+    }
 
-    Ok(())
-}
+    pub fn mutual_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MutualSettle<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        buyer_bps: u16,
+    ) -> Result<()> {
+        handle_mutual_settle(ctx, deal_id, buyer_bps)
+    }
 
-// --- Resolve Handler ---
-#[derive(Accounts)]
-pub struct Resolve<'info> {
-    pub arbiter: Signer<'info>,
-    #[account(
-        mut,
-        constraint = escrow_state.arbiter == arbiter.key() @ EscrowError::Unauthorized,
-        constraint = matches!(escrow_state.status, EscrowStatus::Funded | EscrowStatus::Disputed) @ EscrowError::InvalidState,
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-}
+    pub fn settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Settle<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_settle(ctx, deal_id)
+    }
 
-pub fn handle_resolve(ctx: Context<Resolve>, verdict: u8) -> Result<()> {
-    require!(
-        verdict == VERDICT_RELEASE || verdict == VERDICT_REFUND,
-        EscrowError::InvalidState
-    );
+    pub fn set_payout_address(ctx: Context<SetPayoutAddress>, payout_to: Pubkey) -> Result<()> {
+        handle_set_payout_address(ctx, payout_to)
+    }
 
-    let state = &mut ctx.accounts.escrow_state;
-    state.status = EscrowStatus::Resolved;
-    state.nonce = state
-        .nonce
-        .checked_add(1)
-        .ok_or(EscrowError::InvalidState)?;
-    state._reserved[0] = verdict;
+    pub fn set_refund_address(ctx: Context<SetRefundAddress>, refund_to: Pubkey) -> Result<()> {
+        handle_set_refund_address(ctx, refund_to)
+    }
 
-    emit!(DealResolved { verdict });
+    pub fn change_arbiter(ctx: Context<ChangeArbiter>) -> Result<()> {
+        handle_change_arbiter(ctx)
+    }
 
-    Ok(())
-}
+    pub fn admin_change_arbiter(ctx: Context<AdminChangeArbiter>) -> Result<()> {
+        handle_admin_change_arbiter(ctx)
+    }
 
-// --- Release Handler ---
-#[derive(Accounts)]
-#[instruction(deal_id: [u8; 16])]
-pub struct Release<'info> {
-    #[account(mut)]
-    pub seller: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [
-            b"escrow",
-            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
-        ],
-        bump,
-        constraint = escrow_state.seller == seller.key() @ EscrowError::Unauthorized,
-        constraint = escrow_state.status == EscrowStatus::Resolved @ EscrowError::InvalidState,
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-    /// CHECK: PDA authority for the vault
-    pub vault_authority: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = escrow_state.vault_ata,
-        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
-    )]
-    pub vault_ata: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        constraint = seller_ata.owner == seller.key() @ EscrowError::Unauthorized,
-        constraint = seller_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-    )]
-    pub seller_ata: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
+    pub fn create_milestones(
+        ctx: Context<CreateMilestones>,
+        milestones: Vec<MilestoneTerms>,
+    ) -> Result<()> {
+        handle_create_milestones(ctx, milestones)
+    }
 
-pub fn handle_release(ctx: Context<Release>, _deal_id: [u8; 16]) -> Result<()> {
-    let escrow_state_key = ctx.accounts.escrow_state.key();
-    let bump = ctx.accounts.escrow_state.bump;
-    let bump_seed = [bump];
-    let seeds: [&[u8]; 3] = [
-        b"vault".as_ref(),
-        escrow_state_key.as_ref(),
-        bump_seed.as_ref(),
-    ];
-    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
-
-    let expected_vault = Pubkey::create_program_address(&seeds, &crate::ID)
-        .map_err(|_| error!(EscrowError::VaultOwnerMismatch))?;
-    require_keys_eq!(
-        ctx.accounts.vault_authority.key(),
-        expected_vault,
-        EscrowError::VaultOwnerMismatch
-    );
-
-    let state = &mut ctx.accounts.escrow_state;
-    require!(state._reserved[0] == VERDICT_RELEASE, EscrowError::InvalidState);
-    let amount = state.amount;
-    require!(amount > 0, EscrowError::InsufficientFunds);
-    require!(
-        ctx.accounts.vault_ata.amount >= amount,
-        EscrowError::InsufficientFunds
-    );
-
-    let transfer_accounts = Transfer {
-        from: ctx.accounts.vault_ata.to_account_info(),
-        to: ctx.accounts.seller_ata.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-        &signer_seeds,
-    );
-    token::transfer(cpi_ctx, amount)?;
-
-    state.amount = 0;
-    state.status = EscrowStatus::Released;
-
-    emit!(DealReleased { amount });
-
-    Ok(())
-}
+    pub fn release_milestone<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseMilestone<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        index: u8,
+    ) -> Result<()> {
+        handle_release_milestone(ctx, deal_id, index)
+    }
 
-// --- Refund Handler ---
-#[derive(Accounts)]
-#[instruction(deal_id: [u8; 16])]
-pub struct Refund<'info> {
-    #[account(mut)]
-    pub buyer: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [
-            b"escrow",
-            deal_id.as_ref(), // Verify escrow_state PDA matches deal_id
-        ],
-        bump,
-        constraint = escrow_state.buyer == buyer.key() @ EscrowError::Unauthorized,
-        constraint = escrow_state.status == EscrowStatus::Resolved @ EscrowError::InvalidState,
-    )]
-    pub escrow_state: Account<'info, EscrowState>,
-    /// CHECK: PDA authority for the vault
-    pub vault_authority: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = escrow_state.vault_ata,
-        constraint = vault_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-        constraint = vault_ata.owner == vault_authority.key() @ EscrowError::VaultOwnerMismatch,
-    )]
-    pub vault_ata: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        constraint = buyer_ata.owner == buyer.key() @ EscrowError::Unauthorized,
-        constraint = buyer_ata.mint == escrow_state.mint @ EscrowError::MintMismatch,
-    )]
-    pub buyer_ata: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-}
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, index: u8) -> Result<()> {
+        handle_dispute_milestone(ctx, index)
+    }
 
-pub fn handle_refund(ctx: Context<Refund>, _deal_id: [u8; 16]) -> Result<()> {
-    let escrow_state_key = ctx.accounts.escrow_state.key();
-    let bump = ctx.accounts.escrow_state.bump;
-    let bump_seed = [bump];
-    let seeds: [&[u8]; 3] = [
-        b"vault".as_ref(),
-        escrow_state_key.as_ref(),
-        bump_seed.as_ref(),
-    ];
-    let signer_seeds: [&[&[u8]]; 1] = [&seeds];
-
-    let expected_vault = Pubkey::create_program_address(&seeds, &crate::ID)
-        .map_err(|_| error!(EscrowError::VaultOwnerMismatch))?;
-    require_keys_eq!(
-        ctx.accounts.vault_authority.key(),
-        expected_vault,
-        EscrowError::VaultOwnerMismatch
-    );
-
-    let state = &mut ctx.accounts.escrow_state;
-    require!(state._reserved[0] == VERDICT_REFUND, EscrowError::InvalidState);
-
-    let amount = state.amount;
-    require!(amount > 0, EscrowError::InsufficientFunds);
-    require!(
-        ctx.accounts.vault_ata.amount >= amount,
-        EscrowError::InsufficientFunds
-    );
-
-    let transfer_accounts = Transfer {
-        from: ctx.accounts.vault_ata.to_account_info(),
-        to: ctx.accounts.buyer_ata.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_accounts,
-        &signer_seeds,
-    );
-    token::transfer(cpi_ctx, amount)?;
-
-    state.amount = 0;
-    state.status = EscrowStatus::Refunded;
-
-    emit!(DealRefunded { amount });
-
-    Ok(())
+    pub fn resolve_milestone(
+        ctx: Context<ResolveMilestone>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        index: u8,
+        verdict: u8,
+        buyer_bps: u16,
+    ) -> Result<()> {
+        handle_resolve_milestone(ctx, deal_id, index, verdict, buyer_bps)
+    }
+
+    pub fn vote_milestone(
+        ctx: Context<VoteMilestone>,
+        index: u8,
+        verdict: u8,
+        buyer_bps: u16,
+    ) -> Result<()> {
+        handle_vote_milestone(ctx, index, verdict, buyer_bps)
+    }
+
+    pub fn timeout_milestone(ctx: Context<TimeoutMilestone>, index: u8) -> Result<()> {
+        handle_timeout_milestone(ctx, index)
+    }
+
+    pub fn settle_milestone<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleMilestone<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        index: u8,
+    ) -> Result<()> {
+        handle_settle_milestone(ctx, deal_id, index)
+    }
+
+    pub fn withdraw_vested<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawVested<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_withdraw_vested(ctx, deal_id)
+    }
+
+    pub fn claim_periods<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimPeriods<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_claim_periods(ctx, deal_id)
+    }
+
+    pub fn cancel_subscription<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelSubscription<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_cancel_subscription(ctx, deal_id)
+    }
+
+    pub fn setup_swap(
+        ctx: Context<SetupSwap>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        swap_amount: u64,
+        swap_by: i64,
+    ) -> Result<()> {
+        handle_setup_swap(ctx, deal_id, swap_amount, swap_by)
+    }
+
+    pub fn deposit_swap(
+        ctx: Context<DepositSwap>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_deposit_swap(ctx, deal_id)
+    }
+
+    pub fn execute_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSwap<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_execute_swap(ctx, deal_id)
+    }
+
+    pub fn reclaim_seller_deposit(
+        ctx: Context<ReclaimSellerDeposit>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_reclaim_seller_deposit(ctx, deal_id)
+    }
+
+    pub fn reclaim_buyer_deposit(
+        ctx: Context<ReclaimBuyerDeposit>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_reclaim_buyer_deposit(ctx, deal_id)
+    }
+
+    pub fn post_collateral(
+        ctx: Context<PostCollateral>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_post_collateral(ctx, deal_id)
+    }
+
+    pub fn withdraw_collateral(
+        ctx: Context<WithdrawCollateral>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_withdraw_collateral(ctx, deal_id)
+    }
+
+    pub fn mark_delivered(ctx: Context<MarkDelivered>) -> Result<()> {
+        handle_mark_delivered(ctx)
+    }
+
+    pub fn buyer_cancel<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyerCancel<'info>>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+    ) -> Result<()> {
+        handle_buyer_cancel(ctx, deal_id)
+    }
+
+    pub fn set_payout_split(ctx: Context<SetPayoutSplit>, shares: Vec<PayoutShare>) -> Result<()> {
+        handle_set_payout_split(ctx, shares)
+    }
+
+    pub fn amend(
+        ctx: Context<Amend>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        amount: u64,
+        dispute_by: i64,
+        fund_by: i64,
+        deliver_by: i64,
+    ) -> Result<()> {
+        handle_amend(ctx, deal_id, amount, dispute_by, fund_by, deliver_by)
+    }
+
+    pub fn partial_refund(
+        ctx: Context<PartialRefund>,
+        deal_id: [u8; 16], // UUID as 16 bytes - used to verify escrow_state PDA
+        refund_amount: u64,
+    ) -> Result<()> {
+        handle_partial_refund(ctx, deal_id, refund_amount)
+    }
+
+    pub fn migrate_escrow(ctx: Context<MigrateEscrow>) -> Result<()> {
+        handle_migrate_escrow(ctx)
+    }
 }
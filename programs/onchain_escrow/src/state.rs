@@ -8,6 +8,69 @@ pub enum EscrowStatus {
     Resolved,
     Released,
     Refunded,
+    Settled,
+    PartiallyFunded,
+    Cancelled,
+}
+
+/// Which side's payout the arbiter fee is deducted from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArbiterFeePolicy {
+    LoserPays,
+    SplitEvenly,
+}
+
+/// Who receives refunds of a deal funded by a third party.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RefundPolicy {
+    Buyer,
+    Funder,
+}
+
+/// Where a losing dispute bond goes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BondForfeit {
+    Counterparty,
+    Treasury,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DisputeBondTerms {
+    pub amount: u64,
+    pub forfeit_to: BondForfeit,
+}
+
+/// Arbiter fee chosen at initiate: either `flat` or `bps` of the deal amount.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ArbiterFeeTerms {
+    pub bps: u16,
+    pub flat: u64,
+    pub policy: ArbiterFeePolicy,
+}
+
+/// Linear release window chosen at initiate (`end` = 0 disables vesting).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VestingTerms {
+    pub start: i64,
+    pub end: i64,
+}
+
+/// Periodic billing chosen at initiate (`periods` = 0 disables it). `amount` must
+/// divide evenly into the periods.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SubscriptionTerms {
+    pub start: i64,
+    pub period_secs: i64,
+    pub periods: u32,
+}
+
+/// Liquidated damages chosen at initiate: `bps_per_day` of the deal per day late,
+/// capped at `max_bps` (`deliver_by` = 0 disables them).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LatePenaltyTerms {
+    pub deliver_by: i64,
+    pub bps_per_day: u16,
+    pub max_bps: u16,
 }
 
 #[account]
@@ -21,16 +84,61 @@ pub struct EscrowState {
     pub amount: u64,
     pub fee_bps: u16,
     pub dispute_by: i64,
+    pub fund_by: i64, // Deadline for funding, whole or in installments (0 = none)
+    pub funded_amount: u64,
     pub status: EscrowStatus,
     pub nonce: u64,
     pub created_at: i64,
     pub winner: Pubkey, // Set when resolved
+    pub split_buyer_bps: u16, // Buyer share of the vault for split verdicts
+    pub resolve_by: i64, // Arbiter deadline, set when a dispute is opened (0 = none)
+    pub assign_slot: u64, // Slot whose hash draws a pool arbiter for the dispute (0 = none pending)
+    pub arbiter_fee: u64,
+    pub arbiter_fee_bps: u16, // Set when the fee is a share of `amount`, so amendments rescale it
+    pub arbiter_fee_policy: ArbiterFeePolicy,
+    pub arbiter_fee_earned: bool, // Set when the arbiter resolves a disputed deal
+    pub dispute_bond: u64, // Bond required to open a dispute (0 = none)
+    pub bond_forfeit_to: BondForfeit,
+    pub bond_posted: u64, // Bond held in the vault on top of `amount`
+    pub bond_poster: Pubkey,
+    pub payout_to: Pubkey, // Owner of the token account receiving seller proceeds
+    pub refund_to: Pubkey, // Owner of the token account receiving buyer refunds
+    pub funder: Pubkey, // Wallet that deposited the funds; the buyer unless funded on its behalf
+    pub refund_policy: RefundPolicy,
+    pub vest_start: i64,
+    pub vest_end: i64, // 0 = no vesting
+    pub vest_total: u64, // Amount the schedule vests; only a partial refund lowers it
+    pub vest_withdrawn: u64, // Vested funds already paid to the seller
+    pub vest_frozen_at: i64, // Set when a dispute stops the vesting clock
+    pub vest_claimable: u64, // Vested at freeze time, held in the vault on top of `amount`
+    pub period_start: i64,
+    pub period_secs: i64,
+    pub period_amount: u64,
+    pub periods: u32, // 0 = not a subscription
+    pub periods_claimed: u32,
+    pub swap_mint: Pubkey, // Seller's leg of a two-sided swap (default = not a swap)
+    pub swap_vault_ata: Pubkey,
+    pub swap_amount: u64,
+    pub swap_by: i64, // Deposit deadline for both legs
+    pub swap_deposited: bool, // Seller's leg is in the swap vault
+    pub collateral: u64, // Seller performance bond required before funding (0 = none)
+    pub collateral_posted: u64, // Held in the vault on top of `amount`
+    pub deliver_by: i64, // Delivery deadline for late penalties (0 = none)
+    pub delivered_at: i64, // Set when the seller marks delivery
+    pub disputed_at: i64, // Set when a dispute is opened; stops the late-penalty clock
+    pub penalty_bps_per_day: u16,
+    pub penalty_max_bps: u16,
+    pub cancel_fee_bps: u16, // Paid to the seller when the buyer cancels a funded deal
+    pub has_payout_split: bool, // Seller-side payouts go through the PayoutSplit table
+    pub is_milestone: bool, // Set by `create_milestones`; the deal then pays out tranche by tranche
+    pub referrer: Pubkey, // Owner of the token account receiving the referral share (default = none)
+    pub referral_bps: u16, // Referrer's share of the protocol fee
     pub bump: u8,
     pub _reserved: [u8; 32],
 }
 
 impl EscrowState {
-    pub const VERSION: u8 = 1;
+    pub const VERSION: u8 = 2;
     
     pub const LEN: usize = 8 + // discriminator
         1 + // version
@@ -42,14 +150,782 @@ impl EscrowState {
         8 + // amount
         2 + // fee_bps
         8 + // dispute_by
+        8 + // fund_by
+        8 + // funded_amount
         1 + // status (enum)
         8 + // nonce
         8 + // created_at
         32 + // winner
+        2 + // split_buyer_bps
+        8 + // resolve_by
+        8 + // assign_slot
+        8 + // arbiter_fee
+        2 + // arbiter_fee_bps
+        1 + // arbiter_fee_policy (enum)
+        1 + // arbiter_fee_earned
+        8 + // dispute_bond
+        1 + // bond_forfeit_to (enum)
+        8 + // bond_posted
+        32 + // bond_poster
+        32 + // payout_to
+        32 + // refund_to
+        32 + // funder
+        1 + // refund_policy (enum)
+        8 + // vest_start
+        8 + // vest_end
+        8 + // vest_total
+        8 + // vest_withdrawn
+        8 + // vest_frozen_at
+        8 + // vest_claimable
+        8 + // period_start
+        8 + // period_secs
+        8 + // period_amount
+        4 + // periods
+        4 + // periods_claimed
+        32 + // swap_mint
+        32 + // swap_vault_ata
+        8 + // swap_amount
+        8 + // swap_by
+        1 + // swap_deposited
+        8 + // collateral
+        8 + // collateral_posted
+        8 + // deliver_by
+        8 + // delivered_at
+        8 + // disputed_at
+        2 + // penalty_bps_per_day
+        2 + // penalty_max_bps
+        2 + // cancel_fee_bps
+        1 + // has_payout_split
+        1 + // is_milestone
+        32 + // referrer
+        2 + // referral_bps
         1 + // bump
         32; // _reserved
     
     pub fn space() -> usize {
         Self::LEN
     }
-}
\ No newline at end of file
+
+    /// Whether the party that posted the dispute bond prevailed under the verdict.
+    /// Even splits count as a win for the poster.
+    pub fn bond_poster_prevailed(&self) -> bool {
+        let half = crate::utils::BPS_DENOMINATOR / 2;
+        if self.bond_poster == self.buyer {
+            self.split_buyer_bps >= half
+        } else {
+            self.split_buyer_bps <= half
+        }
+    }
+
+    /// Vested funds the seller has not withdrawn yet, as of `now` or the freeze time.
+    pub fn vested_unwithdrawn(&self, now: i64) -> Result<u64> {
+        if self.vest_end == 0 {
+            return Ok(0);
+        }
+        if self.vest_frozen_at > 0 {
+            return Ok(self.vest_claimable);
+        }
+        let total = self.vest_total;
+        let elapsed = now.clamp(self.vest_start, self.vest_end) - self.vest_start;
+        let duration = self.vest_end - self.vest_start;
+        let vested = (total as u128 * elapsed as u128 / duration as u128) as u64;
+        Ok(vested.saturating_sub(self.vest_withdrawn))
+    }
+
+    /// Stops the vesting clock. Funds vested so far stay withdrawable by the seller and
+    /// leave `amount`, so dispute payouts only cover the unvested remainder. Fails once
+    /// everything has vested, as there is nothing left for a verdict to pay out.
+    pub fn freeze_vesting(&mut self, now: i64) -> Result<()> {
+        if self.vest_end == 0 || self.vest_frozen_at > 0 {
+            return Ok(());
+        }
+        let claimable = self.vested_unwithdrawn(now)?;
+        self.amount = self
+            .amount
+            .checked_sub(claimable)
+            .ok_or(crate::errors::EscrowError::Overflow)?;
+        require!(self.amount > 0, crate::errors::EscrowError::FullyVested);
+        self.vest_claimable = claimable;
+        self.vest_frozen_at = now;
+        Ok(())
+    }
+
+    /// Subscription periods that have elapsed by `now` but not been claimed.
+    pub fn periods_due(&self, now: i64) -> u32 {
+        if self.periods == 0 || now < self.period_start {
+            return 0;
+        }
+        let elapsed = ((now - self.period_start) / self.period_secs).min(self.periods as i64);
+        (elapsed as u32).saturating_sub(self.periods_claimed)
+    }
+
+    /// Whether the deal is a two-sided swap. Swaps settle only through `execute_swap`
+    /// or the reclaim paths, never through a verdict or a seller-side payout.
+    pub fn is_swap(&self) -> bool {
+        self.swap_mint != Pubkey::default()
+    }
+
+    /// Whether the seller has posted any collateral the deal requires.
+    pub fn collateral_ready(&self) -> bool {
+        self.collateral_posted >= self.collateral
+    }
+
+    /// Splits the posted collateral into (buyer, seller) shares under the verdict and
+    /// clears it: the seller gets it back on release and forfeits it on refund.
+    pub fn take_collateral(&mut self) -> Result<(u64, u64)> {
+        let shares = crate::utils::split_amount(self.collateral_posted, self.split_buyer_bps)?;
+        self.collateral_posted = 0;
+        Ok(shares)
+    }
+
+    /// Collateral the buyer takes under a milestone verdict: the tranche's pro-rata slice
+    /// of the required collateral, `buyer_bps` of it, capped at what is still posted.
+    pub fn take_milestone_collateral(
+        &mut self,
+        tranche: u64,
+        schedule_total: u64,
+        buyer_bps: u16,
+    ) -> Result<u64> {
+        if schedule_total == 0 {
+            return Ok(0);
+        }
+        let slice = (self.collateral as u128)
+            .checked_mul(tranche as u128)
+            .ok_or(crate::errors::EscrowError::Overflow)?
+            / schedule_total as u128;
+        let forfeited = crate::utils::bps_of(slice as u64, buyer_bps)?.min(self.collateral_posted);
+        self.collateral_posted -= forfeited;
+        Ok(forfeited)
+    }
+
+    /// Late-delivery penalty on `base`, measured at `delivered_at` or, if the seller never
+    /// marked delivery, when the dispute was opened (else `now`): the seller cannot deliver
+    /// into a frozen deal, so time spent in arbitration is not charged. A partial day
+    /// counts as a full day.
+    pub fn late_penalty(&self, base: u64, now: i64) -> Result<u64> {
+        if self.deliver_by == 0 {
+            return Ok(0);
+        }
+        let delivered = if self.delivered_at > 0 {
+            self.delivered_at
+        } else if self.disputed_at > 0 {
+            self.disputed_at
+        } else {
+            now
+        };
+        if delivered <= self.deliver_by {
+            return Ok(0);
+        }
+        let day = crate::utils::SECONDS_PER_DAY;
+        let days_late = (delivered - self.deliver_by + day - 1) / day;
+        let bps = (days_late as u64)
+            .saturating_mul(self.penalty_bps_per_day as u64)
+            .min(self.penalty_max_bps as u64);
+        crate::utils::bps_of(base, bps as u16)
+    }
+
+    /// Under `LoserPays`, covers what it can of `fee` from deposits the losing side of an
+    /// all-or-nothing verdict forfeits: its dispute bond, then the seller's collateral.
+    /// Returns the rest, which `charge_arbiter_fee` takes from the payouts.
+    pub fn charge_fee_to_loser_deposits(&mut self, fee: u64) -> u64 {
+        let seller_lost = self.split_buyer_bps == crate::utils::BPS_DENOMINATOR;
+        let buyer_lost = self.split_buyer_bps == 0;
+        if self.arbiter_fee_policy != ArbiterFeePolicy::LoserPays || !(seller_lost || buyer_lost)
+        {
+            return fee;
+        }
+        let mut remaining = fee;
+        if self.bond_posted > 0 && !self.bond_poster_prevailed() {
+            let taken = remaining.min(self.bond_posted);
+            self.bond_posted -= taken;
+            remaining -= taken;
+        }
+        if seller_lost {
+            let taken = remaining.min(self.collateral_posted);
+            self.collateral_posted -= taken;
+            remaining -= taken;
+        }
+        remaining
+    }
+
+    /// Arbiter fee owed at settlement; only disputed deals resolved by the arbiter pay it.
+    pub fn arbiter_fee_due(&self) -> u64 {
+        if self.arbiter_fee_earned {
+            self.arbiter_fee
+        } else {
+            0
+        }
+    }
+}
+
+/// Layout of version 1 escrows, before installment funding and the optional deal terms.
+/// Only read by `migrate_escrow`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EscrowStateV1 {
+    pub version: u8,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub arbiter: Pubkey,
+    pub mint: Pubkey,
+    pub vault_ata: Pubkey,
+    pub amount: u64,
+    pub fee_bps: u16,
+    pub dispute_by: i64,
+    pub status: EscrowStatus,
+    pub nonce: u64,
+    pub created_at: i64,
+    pub winner: Pubkey,
+    pub bump: u8,
+    pub _reserved: [u8; 32],
+}
+
+impl EscrowStateV1 {
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = 8 + // discriminator
+        1 + // version
+        32 + // seller
+        32 + // buyer
+        32 + // arbiter
+        32 + // mint
+        32 + // vault_ata
+        8 + // amount
+        2 + // fee_bps
+        8 + // dispute_by
+        1 + // status (enum)
+        8 + // nonce
+        8 + // created_at
+        32 + // winner
+        1 + // bump
+        32; // _reserved
+}
+
+impl From<EscrowStateV1> for EscrowState {
+    /// Version 1 deals were funded in one deposit by the buyer and had none of the
+    /// optional terms, so every new field takes the value `initiate` gives a plain deal.
+    fn from(v1: EscrowStateV1) -> Self {
+        let funded_amount = match v1.status {
+            EscrowStatus::Funded | EscrowStatus::Disputed | EscrowStatus::Resolved => v1.amount,
+            _ => 0,
+        };
+        let funder = if funded_amount > 0 { v1.buyer } else { Pubkey::default() };
+        // Version 1 stored only the verdict; normalize the buyer share the way `resolve` does.
+        let split_buyer_bps = if v1.status == EscrowStatus::Resolved {
+            crate::utils::verdict_buyer_bps(v1._reserved[0], 0).unwrap_or(0)
+        } else {
+            0
+        };
+        Self {
+            version: Self::VERSION,
+            seller: v1.seller,
+            buyer: v1.buyer,
+            arbiter: v1.arbiter,
+            mint: v1.mint,
+            vault_ata: v1.vault_ata,
+            amount: v1.amount,
+            fee_bps: v1.fee_bps,
+            dispute_by: v1.dispute_by,
+            fund_by: 0,
+            funded_amount,
+            status: v1.status,
+            nonce: v1.nonce,
+            created_at: v1.created_at,
+            winner: v1.winner,
+            split_buyer_bps,
+            resolve_by: 0,
+            assign_slot: 0,
+            arbiter_fee: 0,
+            arbiter_fee_bps: 0,
+            arbiter_fee_policy: ArbiterFeePolicy::SplitEvenly,
+            arbiter_fee_earned: false,
+            dispute_bond: 0,
+            bond_forfeit_to: BondForfeit::Counterparty,
+            bond_posted: 0,
+            bond_poster: Pubkey::default(),
+            payout_to: v1.seller,
+            refund_to: v1.buyer,
+            funder,
+            refund_policy: RefundPolicy::Buyer,
+            vest_start: 0,
+            vest_end: 0,
+            vest_total: 0,
+            vest_withdrawn: 0,
+            vest_frozen_at: 0,
+            vest_claimable: 0,
+            period_start: 0,
+            period_secs: 0,
+            period_amount: 0,
+            periods: 0,
+            periods_claimed: 0,
+            swap_mint: Pubkey::default(),
+            swap_vault_ata: Pubkey::default(),
+            swap_amount: 0,
+            swap_by: 0,
+            swap_deposited: false,
+            collateral: 0,
+            collateral_posted: 0,
+            deliver_by: 0,
+            delivered_at: 0,
+            disputed_at: 0,
+            penalty_bps_per_day: 0,
+            penalty_max_bps: 0,
+            cancel_fee_bps: 0,
+            has_payout_split: false,
+            is_milestone: false,
+            referrer: Pubkey::default(),
+            referral_bps: 0,
+            bump: v1.bump,
+            _reserved: v1._reserved,
+        }
+    }
+}
+
+pub const MAX_PANEL_ARBITERS: usize = 7;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PanelVote {
+    pub verdict: u8, // 0 while the arbiter has not voted
+    pub buyer_bps: u16,
+}
+
+#[account]
+pub struct ArbiterPanel {
+    pub escrow: Pubkey,
+    pub threshold: u8,
+    pub arbiter_count: u8,
+    pub arbiters: [Pubkey; MAX_PANEL_ARBITERS],
+    pub votes: [PanelVote; MAX_PANEL_ARBITERS],
+    pub votes_cast: u8,
+    pub bump: u8,
+}
+
+impl ArbiterPanel {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // escrow
+        1 + // threshold
+        1 + // arbiter_count
+        32 * MAX_PANEL_ARBITERS + // arbiters
+        3 * MAX_PANEL_ARBITERS + // votes
+        1 + // votes_cast
+        1; // bump
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+
+    pub fn arbiter_index(&self, key: &Pubkey) -> Option<usize> {
+        self.arbiters[..self.arbiter_count as usize]
+            .iter()
+            .position(|a| a == key)
+    }
+
+    /// Number of cast votes that agree with `vote`.
+    pub fn tally(&self, vote: &PanelVote) -> u8 {
+        self.tally_in(&self.votes, vote)
+    }
+
+    /// Number of votes in `votes` (the deal's or a milestone's) that agree with `vote`.
+    pub fn tally_in(&self, votes: &[PanelVote; MAX_PANEL_ARBITERS], vote: &PanelVote) -> u8 {
+        votes[..self.arbiter_count as usize]
+            .iter()
+            .filter(|v| v == &vote)
+            .count() as u8
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub treasury: Pubkey, // Owner of the token accounts receiving protocol proceeds
+    pub stake_mint: Pubkey,
+    pub min_stake: u64,
+    pub resolution_window: i64, // Seconds the arbiter has to resolve a dispute (0 = unlimited)
+    pub timeout_fallback: TimeoutFallback,
+    pub backup_arbiter: Pubkey,
+    pub max_arbiter_fee_bps: u16,
+    pub max_referral_bps: u16, // Cap on the referrer's share of the protocol fee
+    pub bump: u8,
+    pub _reserved: [u8; 32],
+}
+
+impl Config {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        32 + // treasury
+        32 + // stake_mint
+        8 + // min_stake
+        8 + // resolution_window
+        1 + // timeout_fallback (enum)
+        32 + // backup_arbiter
+        2 + // max_arbiter_fee_bps
+        2 + // max_referral_bps
+        1 + // bump
+        32; // _reserved
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+
+    /// Fallback to apply when `timed_out` misses a deadline on `state`. Escalating to the
+    /// arbiter that just timed out (or to a party) would loop, so that refunds instead.
+    pub fn fallback_for(&self, state: &EscrowState, timed_out: &Pubkey) -> TimeoutFallback {
+        let backup = self.backup_arbiter;
+        if self.timeout_fallback == TimeoutFallback::Escalate
+            && (backup == Pubkey::default()
+                || backup == *timed_out
+                || backup == state.seller
+                || backup == state.buyer)
+        {
+            return TimeoutFallback::RefundBuyer;
+        }
+        self.timeout_fallback
+    }
+
+    /// Resolution deadline for a dispute opened at `now` (0 = unlimited).
+    pub fn resolve_by(&self, now: i64) -> Result<i64> {
+        if self.resolution_window > 0 {
+            Ok(now
+                .checked_add(self.resolution_window)
+                .ok_or(crate::errors::EscrowError::Overflow)?)
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+/// Applied by `timeout_dispute` once a dispute outlives its resolution deadline.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TimeoutFallback {
+    RefundBuyer,
+    SplitEvenly,
+    Escalate,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArbiterStatus {
+    Active,
+    Suspended,
+    Unstaking, // Left the pool; stake withdrawable once the cooldown ends
+}
+
+#[account]
+pub struct ArbiterProfile {
+    pub authority: Pubkey,
+    pub stake_vault: Pubkey,
+    pub staked_amount: u64,
+    pub slashed_amount: u64,
+    pub status: ArbiterStatus,
+    pub cases_handled: u64,
+    pub cases_overturned: u64,
+    pub registered_at: i64,
+    pub unstake_at: i64, // Earliest stake withdrawal, set when the arbiter deactivates (0 = none)
+    pub bump: u8,
+}
+
+impl ArbiterProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority
+        32 + // stake_vault
+        8 + // staked_amount
+        8 + // slashed_amount
+        1 + // status (enum)
+        8 + // cases_handled
+        8 + // cases_overturned
+        8 + // registered_at
+        8 + // unstake_at
+        1; // bump
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+}
+
+pub const MAX_POOL_ARBITERS: usize = 128;
+
+/// Active registered arbiters eligible for random assignment.
+#[account]
+pub struct ArbiterPool {
+    pub arbiters: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl ArbiterPool {
+    pub const LEN: usize = 8 + // discriminator
+        4 + 32 * MAX_POOL_ARBITERS + // arbiters
+        1; // bump
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+
+    /// Adds `arbiter` if it is not already listed; returns false when the pool is full.
+    pub fn add(&mut self, arbiter: Pubkey) -> bool {
+        if self.arbiters.contains(&arbiter) {
+            return true;
+        }
+        if self.arbiters.len() >= MAX_POOL_ARBITERS {
+            return false;
+        }
+        self.arbiters.push(arbiter);
+        true
+    }
+
+    pub fn remove(&mut self, arbiter: &Pubkey) {
+        if let Some(index) = self.arbiters.iter().position(|a| a == arbiter) {
+            self.arbiters.swap_remove(index);
+        }
+    }
+
+    /// Picks the arbiter at `seed % len`, walking forward past excluded keys.
+    pub fn select(&self, seed: u64, exclude: &[Pubkey]) -> Option<Pubkey> {
+        let len = self.arbiters.len();
+        if len == 0 {
+            return None;
+        }
+        let start = (seed % len as u64) as usize;
+        (0..len)
+            .map(|offset| self.arbiters[(start + offset) % len])
+            .find(|candidate| !exclude.contains(candidate))
+    }
+}
+
+pub const MAX_MILESTONES: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+    #[default]
+    Pending,
+    Disputed,
+    Released, // Paid to the seller, on buyer approval or by verdict
+    Refunded,
+    Split,
+    Resolved, // Verdict recorded, waiting for `settle_milestone`
+}
+
+/// Milestone definition supplied when the schedule is created.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MilestoneTerms {
+    pub amount: u64,
+    pub description_hash: [u8; 32],
+    pub due_date: i64, // Approved for the seller if not disputed by then (0 = buyer approval only)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Milestone {
+    pub amount: u64,
+    pub description_hash: [u8; 32],
+    pub due_date: i64,
+    pub status: MilestoneStatus,
+    pub verdict: u8, // Set when a dispute is resolved
+    pub buyer_bps: u16, // Buyer share of the tranche under the verdict
+    pub resolve_by: i64, // Arbiter deadline for a disputed milestone (0 = none)
+    pub votes: [PanelVote; MAX_PANEL_ARBITERS], // Panel votes on a disputed milestone
+}
+
+impl Milestone {
+    pub const LEN: usize = 8 + // amount
+        32 + // description_hash
+        8 + // due_date
+        1 + // status (enum)
+        1 + // verdict
+        2 + // buyer_bps
+        8 + // resolve_by
+        3 * MAX_PANEL_ARBITERS; // votes
+
+    /// Whether the buyer's approval is no longer needed to release the tranche.
+    pub fn approval_lapsed(&self, now: i64) -> bool {
+        self.due_date > 0 && now > self.due_date
+    }
+
+    /// Records the verdict on a disputed milestone; `settle_milestone` pays it out.
+    pub fn record_verdict(&mut self, verdict: u8, buyer_bps: u16) {
+        self.status = MilestoneStatus::Resolved;
+        self.verdict = verdict;
+        self.buyer_bps = buyer_bps;
+        self.resolve_by = 0;
+    }
+}
+
+#[account]
+pub struct MilestoneSchedule {
+    pub escrow: Pubkey,
+    pub count: u8,
+    pub milestones: [Milestone; MAX_MILESTONES],
+    pub bump: u8,
+}
+
+impl MilestoneSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // escrow
+        1 + // count
+        Milestone::LEN * MAX_MILESTONES + // milestones
+        1; // bump
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+
+    pub fn get_mut(&mut self, index: u8) -> Option<&mut Milestone> {
+        self.milestones[..self.count as usize].get_mut(index as usize)
+    }
+
+    /// Sum of every tranche in the schedule, settled or not.
+    pub fn total(&self) -> Result<u64> {
+        self.milestones[..self.count as usize]
+            .iter()
+            .try_fold(0u64, |sum, m| sum.checked_add(m.amount))
+            .ok_or_else(|| error!(crate::errors::EscrowError::Overflow))
+    }
+}
+
+pub const MAX_PAYOUT_RECIPIENTS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PayoutShare {
+    pub recipient: Pubkey, // Owner of the token account receiving the share
+    pub bps: u16,
+}
+
+/// Split table for the seller-side payout. Shares need not add up to 10_000; whatever
+/// is left goes to the seller's payout address.
+#[account]
+pub struct PayoutSplit {
+    pub escrow: Pubkey,
+    pub count: u8,
+    pub shares: [PayoutShare; MAX_PAYOUT_RECIPIENTS],
+    pub bump: u8,
+}
+
+impl PayoutSplit {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // escrow
+        1 + // count
+        34 * MAX_PAYOUT_RECIPIENTS + // shares
+        1; // bump
+
+    pub fn space() -> usize {
+        Self::LEN
+    }
+
+    pub fn active_shares(&self) -> &[PayoutShare] {
+        &self.shares[..self.count as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::SECONDS_PER_DAY;
+
+    fn blank_state() -> EscrowState {
+        EscrowState::try_from_slice(&[0u8; EscrowState::LEN - 8]).unwrap()
+    }
+
+    #[test]
+    fn arbiter_pool_select_skips_excluded_keys() {
+        let mut pool = ArbiterPool {
+            arbiters: vec![],
+            bump: 0,
+        };
+        assert_eq!(pool.select(7, &[]), None);
+
+        let arbiters = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        for arbiter in arbiters {
+            assert!(pool.add(arbiter));
+        }
+        assert_eq!(pool.select(4, &[]), Some(arbiters[1]));
+        assert_eq!(pool.select(4, &[arbiters[1]]), Some(arbiters[2]));
+        assert_eq!(pool.select(5, &[arbiters[2]]), Some(arbiters[0]));
+        assert_eq!(pool.select(5, &arbiters), None);
+    }
+
+    #[test]
+    fn vested_unwithdrawn_is_linear_in_the_stored_total() {
+        let mut state = blank_state();
+        assert_eq!(state.vested_unwithdrawn(50).unwrap(), 0);
+
+        state.vest_start = 100;
+        state.vest_end = 200;
+        state.vest_total = 1_000;
+        state.amount = 1_000;
+        assert_eq!(state.vested_unwithdrawn(50).unwrap(), 0);
+        assert_eq!(state.vested_unwithdrawn(150).unwrap(), 500);
+        assert_eq!(state.vested_unwithdrawn(500).unwrap(), 1_000);
+
+        state.vest_withdrawn = 200;
+        state.amount = 800;
+        assert_eq!(state.vested_unwithdrawn(150).unwrap(), 300);
+    }
+
+    #[test]
+    fn freeze_vesting_moves_vested_funds_out_of_amount() {
+        let mut state = blank_state();
+        state.vest_start = 100;
+        state.vest_end = 200;
+        state.vest_total = 1_000;
+        state.amount = 1_000;
+        state.freeze_vesting(125).unwrap();
+        assert_eq!(state.amount, 750);
+        assert_eq!(state.vest_claimable, 250);
+        assert_eq!(state.vested_unwithdrawn(1_000).unwrap(), 250);
+
+        let mut vested = blank_state();
+        vested.vest_start = 100;
+        vested.vest_end = 200;
+        vested.vest_total = 1_000;
+        vested.amount = 1_000;
+        assert!(vested.freeze_vesting(200).is_err());
+    }
+
+    #[test]
+    fn periods_due_excludes_claimed_and_future_periods() {
+        let mut state = blank_state();
+        assert_eq!(state.periods_due(1_000), 0);
+
+        state.period_start = 1_000;
+        state.period_secs = 100;
+        state.periods = 5;
+        assert_eq!(state.periods_due(999), 0);
+        assert_eq!(state.periods_due(1_099), 0);
+        assert_eq!(state.periods_due(1_250), 2);
+        assert_eq!(state.periods_due(10_000), 5);
+
+        state.periods_claimed = 2;
+        assert_eq!(state.periods_due(1_250), 0);
+        assert_eq!(state.periods_due(10_000), 3);
+    }
+
+    #[test]
+    fn late_penalty_counts_started_days_and_caps() {
+        let mut state = blank_state();
+        assert_eq!(state.late_penalty(10_000, 1_000_000).unwrap(), 0);
+
+        state.deliver_by = 1_000;
+        state.penalty_bps_per_day = 100;
+        state.penalty_max_bps = 500;
+        assert_eq!(state.late_penalty(10_000, 1_000).unwrap(), 0);
+        // A day and a half late counts as two days.
+        let now = 1_000 + SECONDS_PER_DAY * 3 / 2;
+        assert_eq!(state.late_penalty(10_000, now).unwrap(), 200);
+        let much_later = 1_000 + SECONDS_PER_DAY * 30;
+        assert_eq!(state.late_penalty(10_000, much_later).unwrap(), 500);
+
+        state.delivered_at = 1_000 + SECONDS_PER_DAY;
+        assert_eq!(state.late_penalty(10_000, now * 10).unwrap(), 100);
+    }
+
+    #[test]
+    fn late_penalty_stops_at_dispute_open() {
+        let mut state = blank_state();
+        state.deliver_by = 1_000;
+        state.penalty_bps_per_day = 100;
+        state.penalty_max_bps = 10_000;
+        state.disputed_at = 1_000 + SECONDS_PER_DAY * 2;
+        let now = 1_000 + SECONDS_PER_DAY * 40;
+        assert_eq!(state.late_penalty(10_000, now).unwrap(), 200);
+    }
+}
@@ -1,11 +1,586 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+use solana_sha256_hasher::hashv;
 use crate::EscrowError;
+use crate::events::{PayoutSplitPaid, ProtocolFeePaid};
+use crate::state::{
+    ArbiterFeePolicy, ArbiterProfile, ArbiterStatus, EscrowState, PayoutSplit,
+    MAX_PAYOUT_RECIPIENTS,
+};
 
 pub const VERDICT_RELEASE: u8 = 1;
 pub const VERDICT_REFUND: u8 = 2;
+pub const VERDICT_SPLIT: u8 = 3;
+
+pub const BPS_DENOMINATOR: u16 = 10_000;
+pub const SECONDS_PER_DAY: i64 = 86_400;
+/// Slots between opening a dispute and the slot whose hash picks a pool arbiter, so
+/// the party opening the dispute cannot know the outcome when choosing when to send it.
+pub const ARBITER_ASSIGNMENT_DELAY_SLOTS: u64 = 16;
+/// Time a deactivated arbiter's stake stays slashable before it can be withdrawn.
+pub const UNSTAKE_COOLDOWN: i64 = 14 * SECONDS_PER_DAY;
 
 pub fn assert_nonzero(amount: u64) -> Result<()> {
     require!(amount > 0, EscrowError::InsufficientFunds);
     Ok(())
 }
 
+/// Buyer share (in bps) implied by a verdict; `buyer_bps` is only read for splits.
+pub fn verdict_buyer_bps(verdict: u8, buyer_bps: u16) -> Result<u16> {
+    match verdict {
+        VERDICT_RELEASE => Ok(0),
+        VERDICT_REFUND => Ok(BPS_DENOMINATOR),
+        VERDICT_SPLIT => {
+            require!(buyer_bps <= BPS_DENOMINATOR, EscrowError::InvalidVerdict);
+            Ok(buyer_bps)
+        }
+        _ => err!(EscrowError::InvalidVerdict),
+    }
+}
+
+/// Splits `amount` into (buyer, seller) shares. Rounding dust goes to the seller.
+pub fn split_amount(amount: u64, buyer_bps: u16) -> Result<(u64, u64)> {
+    require!(buyer_bps <= BPS_DENOMINATOR, EscrowError::InvalidVerdict);
+    let buyer_amount = bps_of(amount, buyer_bps)?;
+    let seller_amount = amount
+        .checked_sub(buyer_amount)
+        .ok_or(EscrowError::Overflow)?;
+    Ok((buyer_amount, seller_amount))
+}
+
+/// Checks that `profile_info` is the registry profile of `arbiter` and that it is active.
+pub fn assert_active_arbiter(profile_info: &AccountInfo, arbiter: &Pubkey) -> Result<()> {
+    require_keys_eq!(*profile_info.owner, crate::ID, EscrowError::InvalidArbiter);
+    let profile = ArbiterProfile::try_deserialize(&mut &profile_info.data.borrow()[..])?;
+    let expected = Pubkey::create_program_address(
+        &[b"arbiter", arbiter.as_ref(), &[profile.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(EscrowError::InvalidArbiter))?;
+    require_keys_eq!(profile_info.key(), expected, EscrowError::InvalidArbiter);
+    require!(
+        profile.status == ArbiterStatus::Active,
+        EscrowError::InvalidArbiter
+    );
+    Ok(())
+}
+
+/// Address of the vault authority PDA for `escrow`, derived from the stored bump.
+pub fn vault_authority_address(escrow: &Pubkey, bump: u8) -> Result<Pubkey> {
+    Pubkey::create_program_address(&[b"vault", escrow.as_ref(), &[bump]], &crate::ID)
+        .map_err(|_| error!(EscrowError::VaultOwnerMismatch))
+}
+
+/// Signer seeds for the vault authority of `escrow`, checked against the passed account.
+pub fn vault_signer_seeds<'a>(
+    escrow: &'a Pubkey,
+    bump: &'a [u8; 1],
+    vault_authority: &Pubkey,
+) -> Result<[&'a [u8]; 3]> {
+    let seeds: [&[u8]; 3] = [b"vault".as_ref(), escrow.as_ref(), bump.as_ref()];
+    let expected_vault = Pubkey::create_program_address(&seeds, &crate::ID)
+        .map_err(|_| error!(EscrowError::VaultOwnerMismatch))?;
+    require_keys_eq!(*vault_authority, expected_vault, EscrowError::VaultOwnerMismatch);
+    Ok(seeds)
+}
+
+/// Derives the assignment seed from the hash of the first slot at or after `target_slot`
+/// and the escrow key. Returns the seed together with the slot whose hash was used, or
+/// `None` when `target_slot` has already aged out of SlotHashes.
+pub fn arbiter_selection_seed(
+    slot_hashes: &AccountInfo,
+    escrow: &Pubkey,
+    target_slot: u64,
+) -> Result<Option<(u64, u64)>> {
+    // SlotHashes layout: u64 entry count, then (slot: u64, hash: [u8; 32]) entries, newest first.
+    const ENTRY_LEN: usize = 40;
+    let data = slot_hashes.try_borrow_data()?;
+    let count = read_u64(&data, 0)? as usize;
+    let mut chosen = None;
+    for i in 0..count {
+        let offset = 8 + i * ENTRY_LEN;
+        let slot = read_u64(&data, offset)?;
+        if slot < target_slot {
+            break;
+        }
+        chosen = Some((slot, offset + 8));
+    }
+    let oldest = match count {
+        0 => return err!(EscrowError::NoEligibleArbiter),
+        _ => read_u64(&data, 8 + (count - 1) * ENTRY_LEN)?,
+    };
+    let Some((slot, hash_offset)) = chosen else {
+        return err!(EscrowError::DeadlineNotReached);
+    };
+    // The target slot aged out; a later surviving hash may already have been known.
+    if oldest > target_slot {
+        return Ok(None);
+    }
+    let hash = data
+        .get(hash_offset..hash_offset + 32)
+        .ok_or(EscrowError::NoEligibleArbiter)?;
+    let digest = hashv(&[hash, escrow.as_ref()]).to_bytes();
+    Ok(Some((read_u64(&digest, 0)?, slot)))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let bytes = data
+        .get(offset..offset + 8)
+        .and_then(|bytes| <[u8; 8]>::try_from(bytes).ok())
+        .ok_or(EscrowError::NoEligibleArbiter)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Checks that each optional deadline is unset (0) or still ahead of `now`.
+pub fn validate_deadlines(now: i64, deadlines: &[i64]) -> Result<()> {
+    require!(
+        deadlines.iter().all(|&deadline| deadline == 0 || deadline > now),
+        EscrowError::DeadlinePassed
+    );
+    Ok(())
+}
+
+/// `amount * bps / 10_000`, rounded down.
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(EscrowError::Overflow)?
+        / BPS_DENOMINATOR as u128;
+    Ok(value as u64)
+}
+
+/// Deducts the arbiter fee from the (buyer, seller) payouts.
+/// The loser is the side with the smaller share; ties and `SplitEvenly` charge both halves,
+/// with the odd unit on the seller. A side never pays more than its share; any shortfall
+/// is taken from the other side.
+pub fn charge_arbiter_fee(
+    buyer_amount: u64,
+    seller_amount: u64,
+    fee: u64,
+    policy: ArbiterFeePolicy,
+) -> Result<(u64, u64)> {
+    let total = buyer_amount
+        .checked_add(seller_amount)
+        .ok_or(EscrowError::Overflow)?;
+    require!(fee <= total, EscrowError::InsufficientFunds);
+
+    let half = fee / 2;
+    let (buyer_part, seller_part) = match policy {
+        ArbiterFeePolicy::LoserPays if buyer_amount < seller_amount => (fee, 0),
+        ArbiterFeePolicy::LoserPays if seller_amount < buyer_amount => (0, fee),
+        _ => (half, fee - half),
+    };
+
+    let buyer_capped = buyer_part.min(buyer_amount);
+    let seller_part = seller_part + (buyer_part - buyer_capped);
+    let seller_capped = seller_part.min(seller_amount);
+    let buyer_part = buyer_capped + (seller_part - seller_capped);
+
+    Ok((buyer_amount - buyer_part, seller_amount - seller_capped))
+}
+
+/// Transfers `amount` into the program from a token account owned by a signing user.
+pub fn deposit_transfer<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let transfer_accounts = Transfer {
+        from,
+        to,
+        authority,
+    };
+    let cpi_ctx = CpiContext::new(token_program, transfer_accounts);
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Transfers `amount` out of a program-owned token account (the deal vault or an arbiter
+/// stake vault), signed by its PDA authority.
+pub fn vault_transfer<'info>(
+    token_program: AccountInfo<'info>,
+    vault_ata: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    vault_authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let transfer_accounts = Transfer {
+        from: vault_ata,
+        to,
+        authority: vault_authority,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, transfer_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Each split-table recipient's share of `payout`, in table order, and what is left for
+/// the seller. Shares are rounded down; the unallocated bps and all rounding dust stay
+/// with the seller.
+pub fn split_payout(
+    split: &PayoutSplit,
+    payout: u64,
+) -> Result<([u64; MAX_PAYOUT_RECIPIENTS], u64)> {
+    let mut amounts = [0u64; MAX_PAYOUT_RECIPIENTS];
+    let mut remainder = payout;
+    for (amount, share) in amounts.iter_mut().zip(split.active_shares()) {
+        *amount = bps_of(payout, share.bps)?;
+        remainder = remainder
+            .checked_sub(*amount)
+            .ok_or(EscrowError::InvalidPayoutSplit)?;
+    }
+    Ok((amounts, remainder))
+}
+
+/// Pays each split-table recipient its share of `payout` out of the vault.
+/// `recipient_atas` are the recipients' canonical ATAs, in table order. Returns what is
+/// left for the seller (see `split_payout`).
+#[allow(clippy::too_many_arguments)]
+pub fn pay_split_recipients<'info>(
+    token_program: &AccountInfo<'info>,
+    vault_ata: &AccountInfo<'info>,
+    vault_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    split: &PayoutSplit,
+    recipient_atas: &'info [AccountInfo<'info>],
+    mint: &Pubkey,
+    payout: u64,
+) -> Result<u64> {
+    let shares = split.active_shares();
+    require!(
+        recipient_atas.len() == shares.len(),
+        EscrowError::InvalidPayoutSplit
+    );
+
+    let (amounts, remainder) = split_payout(split, payout)?;
+    for ((share, ata_info), amount) in shares.iter().zip(recipient_atas.iter()).zip(amounts) {
+        require_keys_eq!(
+            ata_info.key(),
+            get_associated_token_address(&share.recipient, mint),
+            EscrowError::InvalidPayoutSplit
+        );
+        let ata = Account::<TokenAccount>::try_from(ata_info)?;
+        require_keys_eq!(ata.owner, share.recipient, EscrowError::InvalidPayoutSplit);
+        require_keys_eq!(ata.mint, *mint, EscrowError::MintMismatch);
+
+        if amount == 0 {
+            continue;
+        }
+        vault_transfer(
+            token_program.clone(),
+            vault_ata.clone(),
+            ata_info.clone(),
+            vault_authority.clone(),
+            signer_seeds,
+            amount,
+        )?;
+    }
+
+    emit!(PayoutSplitPaid {
+        escrow: split.escrow,
+        payout,
+        seller_remainder: remainder,
+    });
+
+    Ok(remainder)
+}
+
+/// Splits the protocol fee (`fee_bps` of the seller-side `payout`) into (treasury,
+/// referrer) shares. The referrer, if any, receives `referral_bps` of the fee.
+pub fn protocol_fee_shares(state: &EscrowState, payout: u64) -> Result<(u64, u64)> {
+    let fee = bps_of(payout, state.fee_bps)?;
+    let referral_fee = if state.referrer != Pubkey::default() {
+        bps_of(fee, state.referral_bps)?
+    } else {
+        0
+    };
+    Ok((fee - referral_fee, referral_fee))
+}
+
+/// Takes the protocol fee out of the vault and pays it per `protocol_fee_shares`.
+/// Returns the total fee.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_protocol_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    vault_ata: &AccountInfo<'info>,
+    vault_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    treasury_ata: Option<&Account<'info, TokenAccount>>,
+    referrer_ata: Option<&Account<'info, TokenAccount>>,
+    state: &EscrowState,
+    escrow: Pubkey,
+    payout: u64,
+) -> Result<u64> {
+    let (treasury_fee, referral_fee) = protocol_fee_shares(state, payout)?;
+    let fee = treasury_fee + referral_fee;
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    for (ata, amount) in [(treasury_ata, treasury_fee), (referrer_ata, referral_fee)] {
+        if amount == 0 {
+            continue;
+        }
+        let ata = ata.ok_or(EscrowError::MissingFeeAccount)?;
+        vault_transfer(
+            token_program.clone(),
+            vault_ata.clone(),
+            ata.to_account_info(),
+            vault_authority.clone(),
+            signer_seeds,
+            amount,
+        )?;
+    }
+
+    emit!(ProtocolFeePaid {
+        escrow,
+        treasury_fee,
+        referrer: state.referrer,
+        referral_fee,
+    });
+
+    Ok(fee)
+}
+
+/// Vault-side accounts a payout moves funds through. Recipients other than the parties are
+/// only required when their share is non-zero.
+pub struct PayoutAccounts<'a, 'info> {
+    pub token_program: AccountInfo<'info>,
+    pub vault_ata: AccountInfo<'info>,
+    pub vault_authority: AccountInfo<'info>,
+    pub signer_seeds: &'a [&'a [&'a [u8]]],
+    pub buyer_ata: Option<AccountInfo<'info>>,
+    pub seller_ata: Option<AccountInfo<'info>>,
+    pub arbiter_ata: Option<&'a Account<'info, TokenAccount>>,
+    pub treasury_ata: Option<&'a Account<'info, TokenAccount>>,
+    pub referrer_ata: Option<&'a Account<'info, TokenAccount>>,
+    pub payout_split: Option<&'a Account<'info, PayoutSplit>>,
+    /// Split recipients' canonical ATAs, in table order.
+    pub split_recipients: &'info [AccountInfo<'info>],
+}
+
+/// What `settle_payout` paid each side.
+pub struct Payout {
+    /// Buyer share, including any late penalty.
+    pub buyer_amount: u64,
+    /// Seller share after the protocol fee, before the payout split.
+    pub seller_amount: u64,
+    pub arbiter_fee: u64,
+    pub late_penalty: u64,
+    /// Collateral released by a verdict payout, as (buyer, seller).
+    pub collateral: (u64, u64),
+}
+
+/// Pays `buyer_amount` and `seller_amount` out of the vault.
+/// A `verdict` payout first pays the arbiter fee (see `charge_fee_to_loser_deposits` and
+/// `charge_arbiter_fee`), moves the seller's late penalty to the buyer and releases the
+/// collateral per the verdict. The seller side then pays the protocol fee and the payout
+/// split. Each party also receives its part of `deposits`, which the caller has already
+/// taken off the state.
+pub fn settle_payout(
+    accounts: &PayoutAccounts<'_, '_>,
+    state: &mut EscrowState,
+    escrow: Pubkey,
+    buyer_amount: u64,
+    seller_amount: u64,
+    verdict: bool,
+    deposits: (u64, u64),
+) -> Result<Payout> {
+    let (mut buyer_amount, mut seller_amount) = (buyer_amount, seller_amount);
+    let (mut arbiter_fee, mut late_penalty, mut collateral) = (0, 0, (0, 0));
+    if verdict {
+        let amount = buyer_amount
+            .checked_add(seller_amount)
+            .ok_or(EscrowError::Overflow)?;
+        arbiter_fee = state.arbiter_fee_due();
+        if arbiter_fee > 0 {
+            let arbiter_ata = accounts
+                .arbiter_ata
+                .ok_or(EscrowError::MissingArbiterAccount)?;
+            vault_transfer(
+                accounts.token_program.clone(),
+                accounts.vault_ata.clone(),
+                arbiter_ata.to_account_info(),
+                accounts.vault_authority.clone(),
+                accounts.signer_seeds,
+                arbiter_fee,
+            )?;
+        }
+        let fee_from_payouts = state.charge_fee_to_loser_deposits(arbiter_fee);
+        (buyer_amount, seller_amount) = charge_arbiter_fee(
+            buyer_amount,
+            seller_amount,
+            fee_from_payouts,
+            state.arbiter_fee_policy,
+        )?;
+        late_penalty = state
+            .late_penalty(amount, Clock::get()?.unix_timestamp)?
+            .min(seller_amount);
+        buyer_amount += late_penalty;
+        seller_amount -= late_penalty;
+        collateral = state.take_collateral()?;
+    }
+
+    let protocol_fee = pay_protocol_fee(
+        &accounts.token_program,
+        &accounts.vault_ata,
+        &accounts.vault_authority,
+        accounts.signer_seeds,
+        accounts.treasury_ata,
+        accounts.referrer_ata,
+        state,
+        escrow,
+        seller_amount,
+    )?;
+    let seller_amount = seller_amount - protocol_fee;
+    let seller_remainder = if state.has_payout_split && seller_amount > 0 {
+        let payout_split = accounts
+            .payout_split
+            .ok_or(EscrowError::InvalidPayoutSplit)?;
+        pay_split_recipients(
+            &accounts.token_program,
+            &accounts.vault_ata,
+            &accounts.vault_authority,
+            accounts.signer_seeds,
+            payout_split,
+            accounts.split_recipients,
+            &state.mint,
+            seller_amount,
+        )?
+    } else {
+        seller_amount
+    };
+
+    // The buyer's account is only optional where its share can be nothing but a late
+    // penalty (`release`); the seller's only where it never receives anything (`refund`).
+    for (to, share, missing) in [
+        (
+            accounts.buyer_ata.as_ref(),
+            buyer_amount + collateral.0 + deposits.0,
+            EscrowError::MissingPenaltyAccount,
+        ),
+        (
+            accounts.seller_ata.as_ref(),
+            seller_remainder + collateral.1 + deposits.1,
+            EscrowError::InvalidState,
+        ),
+    ] {
+        if share == 0 {
+            continue;
+        }
+        vault_transfer(
+            accounts.token_program.clone(),
+            accounts.vault_ata.clone(),
+            to.ok_or(missing)?.clone(),
+            accounts.vault_authority.clone(),
+            accounts.signer_seeds,
+            share,
+        )?;
+    }
+
+    Ok(Payout {
+        buyer_amount,
+        seller_amount,
+        arbiter_fee,
+        late_penalty,
+        collateral,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PayoutShare;
+
+    fn split_table(bps: &[u16]) -> PayoutSplit {
+        let mut shares = [PayoutShare::default(); MAX_PAYOUT_RECIPIENTS];
+        for (share, bps) in shares.iter_mut().zip(bps) {
+            share.recipient = Pubkey::new_unique();
+            share.bps = *bps;
+        }
+        PayoutSplit {
+            escrow: Pubkey::new_unique(),
+            count: bps.len() as u8,
+            shares,
+            bump: 0,
+        }
+    }
+
+    fn blank_state() -> EscrowState {
+        EscrowState::try_from_slice(&[0u8; EscrowState::LEN - 8]).unwrap()
+    }
+
+    #[test]
+    fn bps_of_rounds_down_without_overflow() {
+        assert_eq!(bps_of(1_000, 250).unwrap(), 25);
+        assert_eq!(bps_of(999, 1).unwrap(), 0);
+        assert_eq!(bps_of(u64::MAX, BPS_DENOMINATOR).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn split_amount_gives_dust_to_seller() {
+        assert_eq!(split_amount(1_000, 2_500).unwrap(), (250, 750));
+        assert_eq!(split_amount(1_001, 5_000).unwrap(), (500, 501));
+        assert_eq!(split_amount(1_000, 0).unwrap(), (0, 1_000));
+        assert_eq!(split_amount(1_000, BPS_DENOMINATOR).unwrap(), (1_000, 0));
+        assert!(split_amount(1_000, BPS_DENOMINATOR + 1).is_err());
+    }
+
+    #[test]
+    fn charge_arbiter_fee_loser_pays() {
+        let policy = ArbiterFeePolicy::LoserPays;
+        assert_eq!(charge_arbiter_fee(100, 900, 50, policy).unwrap(), (50, 900));
+        assert_eq!(charge_arbiter_fee(900, 100, 50, policy).unwrap(), (900, 50));
+        // The loser's share runs out; the winner covers the shortfall.
+        assert_eq!(charge_arbiter_fee(10, 990, 50, policy).unwrap(), (0, 950));
+        // A tie is charged evenly.
+        assert_eq!(
+            charge_arbiter_fee(500, 500, 50, policy).unwrap(),
+            (475, 475)
+        );
+    }
+
+    #[test]
+    fn charge_arbiter_fee_split_evenly() {
+        let policy = ArbiterFeePolicy::SplitEvenly;
+        assert_eq!(
+            charge_arbiter_fee(500, 500, 51, policy).unwrap(),
+            (475, 474)
+        );
+        assert_eq!(charge_arbiter_fee(0, 1_000, 50, policy).unwrap(), (0, 950));
+        assert_eq!(charge_arbiter_fee(1_000, 0, 51, policy).unwrap(), (949, 0));
+        assert!(charge_arbiter_fee(20, 20, 41, policy).is_err());
+    }
+
+    #[test]
+    fn split_payout_leaves_dust_and_unallocated_bps_to_seller() {
+        let (amounts, remainder) = split_payout(&split_table(&[2_500, 1_000]), 1_001).unwrap();
+        assert_eq!(amounts[..2], [250, 100]);
+        assert_eq!(amounts[2..], [0; MAX_PAYOUT_RECIPIENTS - 2]);
+        assert_eq!(remainder, 651);
+
+        let (_, remainder) = split_payout(&split_table(&[5_000, 5_000]), 1_000).unwrap();
+        assert_eq!(remainder, 0);
+    }
+
+    #[test]
+    fn split_payout_rejects_overallocated_tables() {
+        assert!(split_payout(&split_table(&[6_000, 5_000]), 1_000).is_err());
+    }
+
+    #[test]
+    fn protocol_fee_shares_pay_the_referrer_a_cut() {
+        let mut state = blank_state();
+        state.fee_bps = 100;
+        state.referral_bps = 2_000;
+        assert_eq!(protocol_fee_shares(&state, 10_000).unwrap(), (100, 0));
+
+        state.referrer = Pubkey::new_unique();
+        assert_eq!(protocol_fee_shares(&state, 10_000).unwrap(), (80, 20));
+
+        state.fee_bps = 0;
+        assert_eq!(protocol_fee_shares(&state, 10_000).unwrap(), (0, 0));
+    }
+}
@@ -18,6 +18,24 @@ import { getMockArbiterKeypair } from "./arbiter_keypair";
 
 const VERDICT_RELEASE = 1;
 const VERDICT_REFUND = 2;
+const FEE_BPS = 250;
+const MIN_STAKE = 100;
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111",
+);
+
+// Terms left at their "disabled" defaults by these flows.
+const NO_EXTRAS = {
+  arbiterFee: { bps: 0, flat: new anchor.BN(0), policy: { splitEvenly: {} } },
+  disputeBond: { amount: new anchor.BN(0), forfeitTo: { counterparty: {} } },
+  fundBy: new anchor.BN(0),
+  vesting: { start: new anchor.BN(0), end: new anchor.BN(0) },
+  subscription: { start: new anchor.BN(0), periodSecs: new anchor.BN(0), periods: 0 },
+  collateral: new anchor.BN(0),
+  latePenalty: { deliverBy: new anchor.BN(0), bpsPerDay: 0, maxBps: 0 },
+  cancelFeeBps: 0,
+  referralBps: 0,
+};
 
 describe("onchain-escrow", () => {
   const provider = anchor.AnchorProvider.env();
@@ -30,10 +48,85 @@ describe("onchain-escrow", () => {
     await provider.connection.confirmTransaction(signature, "confirmed");
   }
 
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId,
+  );
+  const arbiter = getMockArbiterKeypair(); // Use deterministic mock arbiter
+  const [arbiterProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("arbiter"), arbiter.publicKey.toBuffer()],
+    program.programId,
+  );
+
+  // Deals require the config, and the arbiter must be registered and staked. The
+  // deploying wallet is the upgrade authority, so it can initialize the config.
+  before(async () => {
+    await airdrop(arbiter.publicKey);
+    if ((await program.account.config.fetchNullable(config)) === null) {
+      const stakeMint = await createMint(
+        provider.connection,
+        payer,
+        payer.publicKey,
+        null,
+        0,
+      );
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_ID,
+      );
+      const [arbiterPool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("arbiter_pool")],
+        program.programId,
+      );
+      await program.methods
+        .initializeConfig(payer.publicKey, new anchor.BN(MIN_STAKE))
+        .accountsPartial({
+          admin: payer.publicKey,
+          program: program.programId,
+          programData,
+          config,
+          arbiterPool,
+          stakeMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const arbiterStakeAta = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        stakeMint,
+        arbiter.publicKey,
+      );
+      await mintTo(
+        provider.connection,
+        payer,
+        stakeMint,
+        arbiterStakeAta.address,
+        payer,
+        MIN_STAKE,
+      );
+      await program.methods
+        .registerArbiter(new anchor.BN(MIN_STAKE))
+        .accountsPartial({
+          arbiter: arbiter.publicKey,
+          config,
+          arbiterPool,
+          arbiterProfile,
+          stakeMint,
+          arbiterAta: arbiterStakeAta.address,
+          stakeVault: getAssociatedTokenAddressSync(stakeMint, arbiterProfile, true),
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([arbiter])
+        .rpc();
+    }
+  });
+
   async function setupEscrowFixture(amount: number) {
     const seller = anchor.web3.Keypair.generate();
     const buyer = anchor.web3.Keypair.generate();
-    const arbiter = getMockArbiterKeypair(); // Use deterministic mock arbiter
 
     // Generate a random 16-byte deal ID
     const dealId = Array.from(randomBytes(16));
@@ -41,7 +134,6 @@ describe("onchain-escrow", () => {
     await Promise.all([
       airdrop(seller.publicKey),
       airdrop(buyer.publicKey),
-      airdrop(arbiter.publicKey),
     ]);
 
     const mint = await createMint(
@@ -76,13 +168,7 @@ describe("onchain-escrow", () => {
     );
 
     const [escrowState] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("escrow"),
-        seller.publicKey.toBuffer(),
-        buyer.publicKey.toBuffer(),
-        mint.toBuffer(),
-        Buffer.from(dealId),
-      ],
+      [Buffer.from("escrow"), Buffer.from(dealId)],
       program.programId,
     );
 
@@ -93,6 +179,14 @@ describe("onchain-escrow", () => {
 
     const vaultAta = getAssociatedTokenAddressSync(mint, vaultAuthority, true);
 
+    // The config's treasury (the provider wallet) collects the protocol fee.
+    const treasuryAta = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      mint,
+      payer.publicKey,
+    );
+
     return {
       seller,
       buyer,
@@ -103,21 +197,152 @@ describe("onchain-escrow", () => {
       escrowState,
       vaultAuthority,
       vaultAta,
+      treasuryAta: treasuryAta.address,
       dealId,
     };
   }
 
+  type Fixture = Awaited<ReturnType<typeof setupEscrowFixture>>;
+
+  async function initiateDeal(
+    fixture: Fixture,
+    amount: number,
+    extras: Partial<typeof NO_EXTRAS> = {},
+  ) {
+    const terms = { ...NO_EXTRAS, ...extras };
+    await program.methods
+      .initiate(
+        new anchor.BN(amount),
+        FEE_BPS,
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        fixture.dealId,
+        terms.arbiterFee,
+        terms.disputeBond,
+        terms.fundBy,
+        terms.vesting,
+        terms.subscription,
+        terms.collateral,
+        terms.latePenalty,
+        terms.cancelFeeBps,
+        terms.referralBps,
+      )
+      .accountsPartial({
+        payer: fixture.seller.publicKey,
+        seller: fixture.seller.publicKey,
+        buyer: fixture.buyer.publicKey,
+        payoutTo: null,
+        referrer: null,
+        arbiter: fixture.arbiter.publicKey,
+        arbiterProfile,
+        config,
+        mint: fixture.mint,
+        escrowState: fixture.escrowState,
+        vaultAuthority: fixture.vaultAuthority,
+        vaultAta: fixture.vaultAta,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([fixture.seller])
+      .rpc();
+  }
+
+  function fundDeal(fixture: Fixture) {
+    return program.methods
+      .fund(fixture.dealId)
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        buyerAta: fixture.buyerAta,
+        vaultAta: fixture.vaultAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  function openDispute(fixture: Fixture) {
+    return program.methods
+      .openDispute()
+      .accountsPartial({
+        caller: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        config,
+        callerAta: null,
+        vaultAta: null,
+        tokenProgram: null,
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  function refundDeal(fixture: Fixture) {
+    return program.methods
+      .refund(fixture.dealId)
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        vaultAuthority: fixture.vaultAuthority,
+        vaultAta: fixture.vaultAta,
+        rentPayer: fixture.buyer.publicKey,
+        mint: fixture.mint,
+        buyerRefund: fixture.buyer.publicKey,
+        buyerAta: fixture.buyerAta,
+        arbiterAta: null,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  // Fails unless `tx` is rejected with the program error `code`.
+  async function expectError(tx: Promise<unknown>, code: string) {
+    try {
+      await tx;
+    } catch (err) {
+      assert.equal((err as anchor.AnchorError).error?.errorCode?.code, code);
+      return;
+    }
+    assert.fail(`expected ${code}`);
+  }
+
+  async function tokenBalance(ata: PublicKey) {
+    return Number((await getAccount(provider.connection, ata)).amount);
+  }
+
   it("happy path release", async () => {
     const amount = 1000;
     const fixture = await setupEscrowFixture(amount);
 
     // Initiate
     await program.methods
-      .initiate(new anchor.BN(amount), 250, new anchor.BN(Math.floor(Date.now() / 1000) + 86400), fixture.dealId)
-      .accounts({
+      .initiate(
+        new anchor.BN(amount),
+        FEE_BPS,
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        fixture.dealId,
+        NO_EXTRAS.arbiterFee,
+        NO_EXTRAS.disputeBond,
+        NO_EXTRAS.fundBy,
+        NO_EXTRAS.vesting,
+        NO_EXTRAS.subscription,
+        NO_EXTRAS.collateral,
+        NO_EXTRAS.latePenalty,
+        NO_EXTRAS.cancelFeeBps,
+        NO_EXTRAS.referralBps,
+      )
+      .accountsPartial({
+        payer: fixture.seller.publicKey,
         seller: fixture.seller.publicKey,
         buyer: fixture.buyer.publicKey,
+        payoutTo: null,
+        referrer: null,
         arbiter: fixture.arbiter.publicKey,
+        arbiterProfile,
+        config,
         mint: fixture.mint,
         escrowState: fixture.escrowState,
         vaultAuthority: fixture.vaultAuthority,
@@ -132,8 +357,8 @@ describe("onchain-escrow", () => {
 
     // Fund
     await program.methods
-      .fund()
-      .accounts({
+      .fund(fixture.dealId)
+      .accountsPartial({
         buyer: fixture.buyer.publicKey,
         escrowState: fixture.escrowState,
         buyerAta: fixture.buyerAta,
@@ -146,23 +371,36 @@ describe("onchain-escrow", () => {
     // Resolve to release
     await program.methods
       .resolve(VERDICT_RELEASE)
-      .accounts({
+      .accountsPartial({
         arbiter: fixture.arbiter.publicKey,
         escrowState: fixture.escrowState,
+        arbiterProfile,
       })
       .signers([fixture.arbiter])
       .rpc();
 
     // Release
     await program.methods
-      .release()
-      .accounts({
+      .release(fixture.dealId)
+      .accountsPartial({
         seller: fixture.seller.publicKey,
         escrowState: fixture.escrowState,
         vaultAuthority: fixture.vaultAuthority,
         vaultAta: fixture.vaultAta,
+        rentPayer: fixture.seller.publicKey,
+        mint: fixture.mint,
+        sellerPayout: fixture.seller.publicKey,
         sellerAta: fixture.sellerAta,
+        buyerRefund: fixture.buyer.publicKey,
+        arbiterAta: null,
+        buyerAta: null,
+        payoutSplit: null,
+        config,
+        treasuryAta: fixture.treasuryAta,
+        referrerAta: null,
+        systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([fixture.seller])
       .rpc();
@@ -174,8 +412,11 @@ describe("onchain-escrow", () => {
     // Updated assertion to just check amount is 0 which implies success
     assert.equal(state.amount.toNumber(), 0);
 
+    const protocolFee = (amount * FEE_BPS) / 10_000;
     const sellerAtaAfter = await getAccount(provider.connection, fixture.sellerAta);
-    assert.equal(Number(sellerAtaAfter.amount), amount); // Seller received funds
+    assert.equal(Number(sellerAtaAfter.amount), amount - protocolFee); // Seller received funds
+    const treasuryAtaAfter = await getAccount(provider.connection, fixture.treasuryAta);
+    assert.ok(Number(treasuryAtaAfter.amount) >= protocolFee); // Shared across tests
   });
 
   it("end-to-end dispute flow with mock arbiter", async () => {
@@ -184,11 +425,30 @@ describe("onchain-escrow", () => {
 
     // 1. Initiate escrow
     await program.methods
-      .initiate(new anchor.BN(amount), 250, new anchor.BN(Math.floor(Date.now() / 1000) + 86400), fixture.dealId)
-      .accounts({
+      .initiate(
+        new anchor.BN(amount),
+        FEE_BPS,
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        fixture.dealId,
+        NO_EXTRAS.arbiterFee,
+        NO_EXTRAS.disputeBond,
+        NO_EXTRAS.fundBy,
+        NO_EXTRAS.vesting,
+        NO_EXTRAS.subscription,
+        NO_EXTRAS.collateral,
+        NO_EXTRAS.latePenalty,
+        NO_EXTRAS.cancelFeeBps,
+        NO_EXTRAS.referralBps,
+      )
+      .accountsPartial({
+        payer: fixture.seller.publicKey,
         seller: fixture.seller.publicKey,
         buyer: fixture.buyer.publicKey,
+        payoutTo: null,
+        referrer: null,
         arbiter: fixture.arbiter.publicKey,
+        arbiterProfile,
+        config,
         mint: fixture.mint,
         escrowState: fixture.escrowState,
         vaultAuthority: fixture.vaultAuthority,
@@ -203,8 +463,8 @@ describe("onchain-escrow", () => {
 
     // 2. Fund escrow
     await program.methods
-      .fund()
-      .accounts({
+      .fund(fixture.dealId)
+      .accountsPartial({
         buyer: fixture.buyer.publicKey,
         escrowState: fixture.escrowState,
         buyerAta: fixture.buyerAta,
@@ -217,9 +477,13 @@ describe("onchain-escrow", () => {
     // 3. Open dispute
     await program.methods
       .openDispute()
-      .accounts({
+      .accountsPartial({
         caller: fixture.buyer.publicKey,
         escrowState: fixture.escrowState,
+        config,
+        callerAta: null,
+        vaultAta: null,
+        tokenProgram: null,
       })
       .signers([fixture.buyer])
       .rpc();
@@ -227,23 +491,30 @@ describe("onchain-escrow", () => {
     // 4. Resolve dispute (arbiter decides to refund)
     await program.methods
       .resolve(VERDICT_REFUND)
-      .accounts({
+      .accountsPartial({
         arbiter: fixture.arbiter.publicKey,
         escrowState: fixture.escrowState,
+        arbiterProfile,
       })
       .signers([fixture.arbiter])
       .rpc();
 
     // 5. Execute refund
     await program.methods
-      .refund()
-      .accounts({
+      .refund(fixture.dealId)
+      .accountsPartial({
         buyer: fixture.buyer.publicKey,
         escrowState: fixture.escrowState,
         vaultAuthority: fixture.vaultAuthority,
         vaultAta: fixture.vaultAta,
+        rentPayer: fixture.buyer.publicKey,
+        mint: fixture.mint,
+        buyerRefund: fixture.buyer.publicKey,
         buyerAta: fixture.buyerAta,
+        arbiterAta: null,
+        systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([fixture.buyer])
       .rpc();
@@ -255,4 +526,472 @@ describe("onchain-escrow", () => {
     const buyerAtaAfter = await getAccount(provider.connection, fixture.buyerAta);
     assert.equal(Number(buyerAtaAfter.amount), amount); // Buyer got refunded
   });
-});
\ No newline at end of file
+
+  // A one-member panel: the mock arbiter is the only registered arbiter available.
+  async function setupPanelDeal(amount: number) {
+    const fixture = await setupEscrowFixture(amount);
+    await initiateDeal(fixture, amount);
+    const [arbiterPanel] = PublicKey.findProgramAddressSync(
+      [Buffer.from("panel"), fixture.escrowState.toBuffer()],
+      program.programId,
+    );
+    await program.methods
+      .createPanel(1, [arbiter.publicKey])
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        seller: fixture.seller.publicKey,
+        escrowState: fixture.escrowState,
+        arbiterPanel,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([{ pubkey: arbiterProfile, isSigner: false, isWritable: false }])
+      .signers([fixture.buyer, fixture.seller])
+      .rpc();
+    await fundDeal(fixture);
+    await openDispute(fixture);
+    return { fixture, arbiterPanel };
+  }
+
+  function vote(fixture: Fixture, arbiterPanel: PublicKey, verdict: number) {
+    return program.methods
+      .vote(verdict, 0)
+      .accountsPartial({
+        arbiter: arbiter.publicKey,
+        escrowState: fixture.escrowState,
+        arbiterPanel,
+        arbiterProfile,
+      })
+      .signers([arbiter])
+      .rpc();
+  }
+
+  it("panel vote resolves a dispute", async () => {
+    const amount = 1000;
+    const { fixture, arbiterPanel } = await setupPanelDeal(amount);
+
+    await vote(fixture, arbiterPanel, VERDICT_REFUND);
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.deepEqual(state.status, { resolved: {} });
+
+    await refundDeal(fixture);
+    assert.equal(await tokenBalance(fixture.buyerAta), amount);
+  });
+
+  it("panel deal rejects bad votes and single-arbiter resolve", async () => {
+    const { fixture, arbiterPanel } = await setupPanelDeal(1000);
+
+    await expectError(vote(fixture, arbiterPanel, 9), "InvalidVerdict");
+    // The panel PDA is the deal's arbiter now.
+    await expectError(
+      program.methods
+        .resolve(VERDICT_REFUND)
+        .accountsPartial({
+          arbiter: arbiter.publicKey,
+          escrowState: fixture.escrowState,
+          arbiterProfile,
+        })
+        .signers([arbiter])
+        .rpc(),
+      "Unauthorized",
+    );
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.deepEqual(state.status, { disputed: {} });
+  });
+
+  function setDisputePolicy(
+    resolutionWindow: number,
+    timeoutFallback: object,
+    backupArbiter = PublicKey.default,
+  ) {
+    const backupProfile = backupArbiter.equals(PublicKey.default)
+      ? []
+      : [{ pubkey: arbiterProfile, isSigner: false, isWritable: false }];
+    return program.methods
+      .updateDisputePolicy(new anchor.BN(resolutionWindow), timeoutFallback as any, backupArbiter)
+      .accountsPartial({ admin: payer.publicKey, config })
+      .remainingAccounts(backupProfile)
+      .rpc();
+  }
+
+  function timeoutDispute(fixture: Fixture) {
+    return program.methods
+      .timeoutDispute()
+      .accountsPartial({
+        caller: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        config,
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  it("timeout refunds the buyer once the arbiter deadline passes", async () => {
+    const amount = 1000;
+    const fixture = await setupEscrowFixture(amount);
+    await setDisputePolicy(2, { refundBuyer: {} });
+    try {
+      await initiateDeal(fixture, amount);
+      await fundDeal(fixture);
+      await openDispute(fixture);
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+
+      await timeoutDispute(fixture);
+      const state = await program.account.escrowState.fetch(fixture.escrowState);
+      assert.deepEqual(state.status, { resolved: {} });
+
+      await refundDeal(fixture);
+      assert.equal(await tokenBalance(fixture.buyerAta), amount);
+    } finally {
+      await setDisputePolicy(0, { refundBuyer: {} });
+    }
+  });
+
+  it("timeout rejects early calls and escalation without a window", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await setDisputePolicy(3600, { refundBuyer: {} });
+    try {
+      await initiateDeal(fixture, 1000);
+      await fundDeal(fixture);
+      await openDispute(fixture);
+      await expectError(timeoutDispute(fixture), "DeadlineNotReached");
+
+      await expectError(
+        setDisputePolicy(0, { escalate: {} }, arbiter.publicKey),
+        "InvalidState",
+      );
+    } finally {
+      await setDisputePolicy(0, { refundBuyer: {} });
+    }
+  });
+
+  // Accounts shared by seller-side payouts: the protocol fee goes to the treasury and
+  // the deals carry no split or referrer.
+  function sellerPayoutAccounts(fixture: Fixture) {
+    return {
+      vaultAuthority: fixture.vaultAuthority,
+      vaultAta: fixture.vaultAta,
+      mint: fixture.mint,
+      sellerPayout: fixture.seller.publicKey,
+      sellerAta: fixture.sellerAta,
+      payoutSplit: null,
+      config,
+      treasuryAta: fixture.treasuryAta,
+      referrerAta: null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    };
+  }
+
+  async function setupMilestoneDeal(tranches: number[]) {
+    const amount = tranches.reduce((sum, tranche) => sum + tranche, 0);
+    const fixture = await setupEscrowFixture(amount);
+    await initiateDeal(fixture, amount);
+    const [milestoneSchedule] = PublicKey.findProgramAddressSync(
+      [Buffer.from("milestones"), fixture.escrowState.toBuffer()],
+      program.programId,
+    );
+    await program.methods
+      .createMilestones(
+        tranches.map((tranche) => ({
+          amount: new anchor.BN(tranche),
+          descriptionHash: Array(32).fill(0),
+          dueDate: new anchor.BN(0),
+        })),
+      )
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        seller: fixture.seller.publicKey,
+        escrowState: fixture.escrowState,
+        milestoneSchedule,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([fixture.buyer, fixture.seller])
+      .rpc();
+    await fundDeal(fixture);
+    return { fixture, milestoneSchedule };
+  }
+
+  it("milestone release pays out one tranche", async () => {
+    const { fixture, milestoneSchedule } = await setupMilestoneDeal([400, 600]);
+
+    await program.methods
+      .releaseMilestone(fixture.dealId, 0)
+      .accountsPartial({
+        caller: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        milestoneSchedule,
+        ...sellerPayoutAccounts(fixture),
+      })
+      .signers([fixture.buyer])
+      .rpc();
+
+    const protocolFee = (400 * FEE_BPS) / 10_000;
+    assert.equal(await tokenBalance(fixture.sellerAta), 400 - protocolFee);
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.equal(state.amount.toNumber(), 600);
+    assert.deepEqual(state.status, { funded: {} });
+  });
+
+  it("milestone deal rejects whole-deal disputes", async () => {
+    const { fixture } = await setupMilestoneDeal([400, 600]);
+
+    await expectError(openDispute(fixture), "InvalidMilestone");
+  });
+
+  async function setupSubscriptionDeal(amount: number, start: number) {
+    const fixture = await setupEscrowFixture(amount);
+    await initiateDeal(fixture, amount, {
+      subscription: { start: new anchor.BN(start), periodSecs: new anchor.BN(5), periods: 2 },
+    });
+    await fundDeal(fixture);
+    return fixture;
+  }
+
+  function claimPeriods(fixture: Fixture) {
+    return program.methods
+      .claimPeriods(fixture.dealId)
+      .accountsPartial({
+        caller: fixture.seller.publicKey,
+        escrowState: fixture.escrowState,
+        ...sellerPayoutAccounts(fixture),
+      })
+      .signers([fixture.seller])
+      .rpc();
+  }
+
+  it("subscription claims every elapsed period", async () => {
+    const amount = 1000;
+    const now = Math.floor(Date.now() / 1000);
+    const fixture = await setupSubscriptionDeal(amount, now - 60);
+
+    await claimPeriods(fixture);
+
+    const protocolFee = (amount * FEE_BPS) / 10_000;
+    assert.equal(await tokenBalance(fixture.sellerAta), amount - protocolFee);
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.deepEqual(state.status, { released: {} });
+  });
+
+  it("subscription rejects claims before the first period and refunds on cancel", async () => {
+    const amount = 1000;
+    const now = Math.floor(Date.now() / 1000);
+    const fixture = await setupSubscriptionDeal(amount, now + 3600);
+
+    await expectError(claimPeriods(fixture), "DeadlineNotReached");
+
+    await program.methods
+      .cancelSubscription(fixture.dealId)
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        buyerRefund: fixture.buyer.publicKey,
+        buyerAta: fixture.buyerAta,
+        ...sellerPayoutAccounts(fixture),
+      })
+      .signers([fixture.buyer])
+      .rpc();
+    assert.equal(await tokenBalance(fixture.buyerAta), amount);
+  });
+
+  // The buyer's leg is the deal mint; the seller's leg is a second mint.
+  async function setupSwapDeal(amount: number, swapAmount: number) {
+    const fixture = await setupEscrowFixture(amount);
+    await initiateDeal(fixture, amount);
+    const swapMint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+    const sellerSwapAta = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      swapMint,
+      fixture.seller.publicKey,
+    );
+    await mintTo(provider.connection, payer, swapMint, sellerSwapAta.address, payer, swapAmount);
+    const swapVaultAta = getAssociatedTokenAddressSync(swapMint, fixture.vaultAuthority, true);
+
+    await program.methods
+      .setupSwap(
+        fixture.dealId,
+        new anchor.BN(swapAmount),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+      )
+      .accountsPartial({
+        seller: fixture.seller.publicKey,
+        buyer: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        vaultAuthority: fixture.vaultAuthority,
+        swapMint,
+        swapVaultAta,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([fixture.seller, fixture.buyer])
+      .rpc();
+    await fundDeal(fixture);
+    return { fixture, swapMint, sellerSwapAta: sellerSwapAta.address, swapVaultAta };
+  }
+
+  function executeSwap(swap: Awaited<ReturnType<typeof setupSwapDeal>>) {
+    const { fixture, swapMint, swapVaultAta } = swap;
+    return program.methods
+      .executeSwap(fixture.dealId)
+      .accountsPartial({
+        caller: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        swapVaultAta,
+        swapMint,
+        buyer: fixture.buyer.publicKey,
+        buyerAta: getAssociatedTokenAddressSync(swapMint, fixture.buyer.publicKey),
+        ...sellerPayoutAccounts(fixture),
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  it("swap exchanges both legs", async () => {
+    const amount = 1000;
+    const swapAmount = 500;
+    const swap = await setupSwapDeal(amount, swapAmount);
+
+    await program.methods
+      .depositSwap(swap.fixture.dealId)
+      .accountsPartial({
+        seller: swap.fixture.seller.publicKey,
+        escrowState: swap.fixture.escrowState,
+        sellerAta: swap.sellerSwapAta,
+        swapVaultAta: swap.swapVaultAta,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([swap.fixture.seller])
+      .rpc();
+    await executeSwap(swap);
+
+    const protocolFee = (amount * FEE_BPS) / 10_000;
+    assert.equal(await tokenBalance(swap.fixture.sellerAta), amount - protocolFee);
+    const buyerSwapAta = getAssociatedTokenAddressSync(swap.swapMint, swap.fixture.buyer.publicKey);
+    assert.equal(await tokenBalance(buyerSwapAta), swapAmount);
+  });
+
+  it("swap rejects execution before the seller's leg is deposited", async () => {
+    const swap = await setupSwapDeal(1000, 500);
+
+    await expectError(executeSwap(swap), "InvalidState");
+  });
+
+  function buyerCancel(fixture: Fixture) {
+    return program.methods
+      .buyerCancel(fixture.dealId)
+      .accountsPartial({
+        buyer: fixture.buyer.publicKey,
+        seller: null,
+        escrowState: fixture.escrowState,
+        buyerRefund: fixture.buyer.publicKey,
+        buyerAta: fixture.buyerAta,
+        ...sellerPayoutAccounts(fixture),
+      })
+      .signers([fixture.buyer])
+      .rpc();
+  }
+
+  it("buyer cancel refunds the deal less the cancellation fee", async () => {
+    const amount = 1000;
+    const fixture = await setupEscrowFixture(amount);
+    await initiateDeal(fixture, amount, { cancelFeeBps: 1000 });
+    await fundDeal(fixture);
+
+    await buyerCancel(fixture);
+
+    const cancelFee = (amount * 1000) / 10_000;
+    const protocolFee = Math.floor((cancelFee * FEE_BPS) / 10_000);
+    assert.equal(await tokenBalance(fixture.buyerAta), amount - cancelFee);
+    assert.equal(await tokenBalance(fixture.sellerAta), cancelFee - protocolFee);
+  });
+
+  it("buyer cancel rejects unfunded deals", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await initiateDeal(fixture, 1000, { cancelFeeBps: 1000 });
+
+    await expectError(buyerCancel(fixture), "InvalidState");
+  });
+
+  function amendDeal(fixture: Fixture, amount: number) {
+    return program.methods
+      .amend(
+        fixture.dealId,
+        new anchor.BN(amount),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0),
+        new anchor.BN(0),
+      )
+      .accountsPartial({
+        seller: fixture.seller.publicKey,
+        buyer: fixture.buyer.publicKey,
+        escrowState: fixture.escrowState,
+        config,
+      })
+      .signers([fixture.seller, fixture.buyer])
+      .rpc();
+  }
+
+  it("amend changes the terms before funding", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await initiateDeal(fixture, 1000);
+    const before = await program.account.escrowState.fetch(fixture.escrowState);
+
+    await amendDeal(fixture, 800);
+
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.equal(state.amount.toNumber(), 800);
+    assert.equal(state.nonce.toNumber(), before.nonce.toNumber() + 1);
+    await fundDeal(fixture);
+    assert.equal(await tokenBalance(fixture.vaultAta), 800);
+  });
+
+  it("amend rejects funded deals", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await initiateDeal(fixture, 1000);
+    await fundDeal(fixture);
+
+    await expectError(amendDeal(fixture, 800), "InvalidState");
+  });
+
+  function partialRefund(fixture: Fixture, refundAmount: number) {
+    return program.methods
+      .partialRefund(fixture.dealId, new anchor.BN(refundAmount))
+      .accountsPartial({
+        seller: fixture.seller.publicKey,
+        escrowState: fixture.escrowState,
+        vaultAuthority: fixture.vaultAuthority,
+        vaultAta: fixture.vaultAta,
+        mint: fixture.mint,
+        buyerRefund: fixture.buyer.publicKey,
+        buyerAta: fixture.buyerAta,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([fixture.seller])
+      .rpc();
+  }
+
+  it("partial refund returns part of the deal to the buyer", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await initiateDeal(fixture, 1000);
+    await fundDeal(fixture);
+
+    await partialRefund(fixture, 300);
+
+    assert.equal(await tokenBalance(fixture.buyerAta), 300);
+    const state = await program.account.escrowState.fetch(fixture.escrowState);
+    assert.equal(state.amount.toNumber(), 700);
+    assert.deepEqual(state.status, { funded: {} });
+  });
+
+  it("partial refund rejects refunding the whole deal", async () => {
+    const fixture = await setupEscrowFixture(1000);
+    await initiateDeal(fixture, 1000);
+    await fundDeal(fixture);
+
+    await expectError(partialRefund(fixture, 1000), "InsufficientFunds");
+  });
+});